@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! An example of a plugin for `reo`, with the only atom
+//! `org.example.double`, which multiplies its `α0` by two:
+//!
+//! ```text
+//! reo dataize --plugin target/debug/libreo_double.so app.reo foo
+//! ```
+
+use reo::plugin::{ReoApi, ReoCall, ReoHost, REO_ABI_VERSION};
+use std::ffi::{c_int, CString};
+
+/// Return from the atom if the call to the API failed.
+macro_rules! check {
+    ($e:expr) => {
+        if $e != 0 {
+            return -1;
+        }
+    };
+}
+
+/// Register all atoms of the plugin.
+///
+/// # Safety
+///
+/// The `host` must be a valid pointer provided by `reo`.
+#[no_mangle]
+pub unsafe extern "C" fn reo_plugin_init(host: *const ReoHost) -> c_int {
+    let host = &*host;
+    if host.abi != REO_ABI_VERSION {
+        return -1;
+    }
+    (host.register)(host.registrar, c"org.example.double".as_ptr(), double)
+}
+
+/// EO atom `org.example.double`.
+unsafe extern "C" fn double(
+    api: *const ReoApi,
+    call: *mut ReoCall,
+    v: u32,
+    out: *mut u32,
+) -> c_int {
+    let api = &*api;
+    let Ok(loc) = CString::new(format!("ν{v}.α0")) else {
+        return -1;
+    };
+    let mut buf = [0u8; 8];
+    let mut len = 0;
+    check!((api.dataize)(
+        call,
+        loc.as_ptr(),
+        buf.as_mut_ptr(),
+        buf.len(),
+        &mut len
+    ));
+    if len != buf.len() {
+        let msg = format!("Eight bytes expected in ν{v}.α0, while {len} provided");
+        if let Ok(m) = CString::new(msg) {
+            (api.fail)(call, m.as_ptr());
+        }
+        return -1;
+    }
+    let Some(x) = i64::from_be_bytes(buf).checked_mul(2) else {
+        (api.fail)(call, c"Integer overflow".as_ptr());
+        return -1;
+    };
+    let data = x.to_be_bytes();
+    let mut int = 0;
+    check!((api.find)(call, c"org.eolang.int".as_ptr(), &mut int));
+    let mut r = 0;
+    check!((api.add)(call, &mut r));
+    check!((api.bind)(call, r, int, c"π".as_ptr()));
+    let mut d = 0;
+    check!((api.add)(call, &mut d));
+    check!((api.put)(call, d, data.as_ptr(), data.len()));
+    check!((api.bind)(call, r, d, c"Δ".as_ptr()));
+    *out = r;
+    0
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::scripts::{copy_of_array, copy_of_string};
+use crate::Universe;
+use anyhow::Result;
+
+impl Universe {
+    /// Make an EO array of strings and put it to `Φ.org.eolang.sys.args`,
+    /// so that the program may read its command line arguments. The
+    /// `org.eolang.array` and `org.eolang.string` must already be in
+    /// the Universe, while `Φ.org.eolang.sys` is created, if absent.
+    /// The ID of the array is returned.
+    pub fn expose_args(&mut self, args: &[String]) -> Result<u32> {
+        let mut items = vec![];
+        for a in args {
+            items.push(copy_of_string(self, a.as_str())?);
+        }
+        let arr = copy_of_array(self, &items)?;
+        let mut v = 0;
+        for a in ["org", "eolang", "sys"] {
+            v = match self.kid(v, a) {
+                Some(k) => k,
+                None => {
+                    let k = self.add()?;
+                    self.bind(v, k, a)?;
+                    self.bind(k, v, "ρ")?;
+                    k
+                }
+            };
+        }
+        self.bind(v, arr, "args")?;
+        self.bind(arr, v, "ρ")?;
+        Ok(arr)
+    }
+}
+
+/// Make `Φ.org.eolang.array` and `Φ.org.eolang.string`, without `sys`.
+#[cfg(test)]
+fn with_eolang() -> Result<Universe> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    for a in ["array", "string"] {
+        let v = uni.add()?;
+        uni.bind(eolang, v, a)?;
+    }
+    Ok(uni)
+}
+
+#[test]
+fn exposes_args() -> Result<()> {
+    let mut uni = with_eolang()?;
+    let arr = uni.expose_args(&["hello".to_string(), "мир".to_string()])?;
+    assert_eq!(arr, uni.find("Φ.org.eolang.sys.args")?);
+    assert_eq!(
+        uni.find("Φ.org.eolang.array")?,
+        uni.find("Φ.org.eolang.sys.args.π")?
+    );
+    assert_eq!("hello", uni.dataize("Φ.org.eolang.sys.args.α0")?.to_utf8()?);
+    assert_eq!("мир", uni.dataize("Φ.org.eolang.sys.args.α1")?.to_utf8()?);
+    assert!(uni.kid(arr, "α2").is_none());
+    Ok(())
+}
+
+#[test]
+fn exposes_no_args() -> Result<()> {
+    let mut uni = with_eolang()?;
+    let arr = uni.expose_args(&[])?;
+    assert!(uni.kid(arr, "α0").is_none());
+    Ok(())
+}
+
+#[test]
+fn fails_without_array() -> Result<()> {
+    let mut uni = Universe::empty();
+    uni.add()?;
+    assert!(uni.expose_args(&["x".to_string()]).is_err());
+    Ok(())
+}
@@ -8,19 +8,25 @@ use anyhow::{anyhow, Context};
 use clap::builder::TypedValueParser;
 use clap::parser::ValuesRef;
 use clap::ErrorKind::EmptyValue;
-use clap::{crate_version, value_parser, AppSettings, Arg, ArgAction, Command};
+use clap::{crate_version, value_parser, AppSettings, Arg, ArgAction, ArgMatches, Command};
 use colored::Colorize;
+use glob::glob;
 use itertools::Itertools;
 use log::{debug, info, warn, LevelFilter};
 use reo::org::eolang::register;
-use reo::Universe;
+use reo::{
+    decompile, junit, lint, DataType, Debugger, DotSink, Graph, HtmlSink, JournalSink, ReoError,
+    Severity, SnapshotSink, TraceFormat, Tracer, Universe,
+};
 use simple_logger::SimpleLogger;
 use sodg::Script;
 use sodg::Sodg;
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::time::Instant;
 use std::{fs, io};
 
@@ -71,6 +77,21 @@ pub fn main() -> Result<()> {
         )
         .subcommand_required(true)
         .allow_external_subcommands(true)
+        .subcommand(
+            Command::new("check")
+                .setting(AppSettings::ColorNever)
+                .about("Find the atoms that are referred to in .reo file, but are not available")
+                .arg(eoc_arg())
+                .arg(plugin_arg())
+                .arg(
+                    Arg::new("file")
+                        .required(false)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file to check (omitted with --eoc)")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                ),
+        )
         .subcommand(
             Command::new("compile")
                 .setting(AppSettings::ColorNever)
@@ -93,6 +114,28 @@ pub fn main() -> Result<()> {
                 )
                 .arg_required_else_help(true),
         )
+        .subcommand(
+            Command::new("decompile")
+                .setting(AppSettings::ColorNever)
+                .about("Turn binary .reo file back into .sodg file")
+                .arg(
+                    Arg::new("bin")
+                        .required(true)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file to use")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("source")
+                        .value_parser(PathValueParser {})
+                        .help("Name of a .sodg file to create; prints to stdout when omitted")
+                        .takes_value(true)
+                        .required(false)
+                        .action(ArgAction::Set),
+                )
+                .arg_required_else_help(true),
+        )
         .subcommand(
             Command::new("empty")
                 .setting(AppSettings::ColorNever)
@@ -106,6 +149,20 @@ pub fn main() -> Result<()> {
                         .action(ArgAction::Set),
                 ),
         )
+        .subcommand(
+            Command::new("lint")
+                .setting(AppSettings::ColorNever)
+                .about("Check binary .reo file for structural problems")
+                .arg(
+                    Arg::new("bin")
+                        .required(true)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file to check")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg_required_else_help(true),
+        )
         .subcommand(
             Command::new("merge")
                 .setting(AppSettings::ColorNever)
@@ -174,6 +231,104 @@ pub fn main() -> Result<()> {
                         .help("Dump the entire graph to a file, when dataization is finished")
                         .action(ArgAction::Set),
                 )
+                .args(limit_args())
+                .arg(
+                    Arg::new("snapshots")
+                        .long("snapshots")
+                        .required(false)
+                        .value_parser(PathValueParser {})
+                        .help("Save snapshots of the graph, at every step of dataization, to this directory")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("snapshot-format")
+                        .long("snapshot-format")
+                        .required(false)
+                        .value_parser(["html", "dot", "journal"])
+                        .default_value("html")
+                        .help("Format of --snapshots: one HTML file, .dot files with a Makefile, or a journal of changes for 'reo replay'")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("trace-out")
+                        .long("trace-out")
+                        .required(false)
+                        .value_parser(PathValueParser {})
+                        .help("Write all steps of dataization to this file")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("trace-format")
+                        .long("trace-format")
+                        .required(false)
+                        .value_parser(["jsonl", "chrome"])
+                        .default_value("jsonl")
+                        .help("Format of --trace-out: JSON Lines or Chrome trace (for Perfetto)")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("gc-threshold")
+                        .long("gc-threshold")
+                        .required(false)
+                        .value_parser(value_parser!(usize))
+                        .help("Collect garbage every time this many vertices are created (0 turns it off)")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("no-memo")
+                        .long("no-memo")
+                        .required(false)
+                        .help("Don't memoize the data found for objects, dataize them every time")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("as")
+                        .long("as")
+                        .required(false)
+                        .value_parser(["auto", "int", "float", "string", "bool", "hex"])
+                        .default_value("hex")
+                        .help("How to decode the result; 'auto' infers it from the π chain of the object")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .required(false)
+                        .help("Print the result as JSON, with its type, bytes, and decoded value")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(plugin_arg())
+                .arg(eoc_arg())
+                .arg(
+                    Arg::new("file")
+                        .required(false)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file to use, or the object name with --eoc")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("object")
+                        .required(false)
+                        .help("Fully qualified object name")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("args")
+                        .required(false)
+                        .last(true)
+                        .multiple_values(true)
+                        .help("Arguments for the program, after '--', available as Φ.org.eolang.sys.args")
+                        .action(ArgAction::Append),
+                )
+                .arg_required_else_help(true),
+        )
+        .subcommand(
+            Command::new("debug")
+                .setting(AppSettings::ColorNever)
+                .about("Dataize an object in .reo file, step by step, asking what to do next")
+                .args(limit_args())
+                .arg(plugin_arg())
                 .arg(
                     Arg::new("file")
                         .required(true)
@@ -231,6 +386,71 @@ pub fn main() -> Result<()> {
                 )
                 .arg_required_else_help(true),
         )
+        .subcommand(
+            Command::new("replay")
+                .setting(AppSettings::ColorNever)
+                .about("Rebuild the graph at a step of dataization, from the journal of snapshots")
+                .arg(
+                    Arg::new("step")
+                        .long("step")
+                        .short('s')
+                        .required(false)
+                        .value_parser(value_parser!(usize))
+                        .help("The number of the step to stop at; the last one when omitted")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("journal")
+                        .required(true)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a journal file, made by 'dataize --snapshot-format=journal'")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("bin")
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file to create; prints DOT to stdout when omitted")
+                        .takes_value(true)
+                        .required(false)
+                        .action(ArgAction::Set),
+                )
+                .arg_required_else_help(true),
+        )
+        .subcommand(
+            Command::new("test")
+                .setting(AppSettings::ColorNever)
+                .about("Dataize all objects that have the 'expected' attribute and compare with it")
+                .args(limit_args())
+                .arg(
+                    Arg::new("filter")
+                        .long("filter")
+                        .required(false)
+                        .default_value("*")
+                        .help("Glob pattern for the names of the objects to test, like 'org.example.*'")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("junit")
+                        .long("junit")
+                        .required(false)
+                        .value_parser(PathValueParser {})
+                        .help("Name of an XML file to save the JUnit report to")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg(plugin_arg())
+                .arg(
+                    Arg::new("file")
+                        .required(true)
+                        .value_parser(PathValueParser {})
+                        .help("Name of a binary .reo file with the tests")
+                        .takes_value(true)
+                        .action(ArgAction::Set),
+                )
+                .arg_required_else_help(true),
+        )
         .get_matches();
     let mut logger = SimpleLogger::new().without_timestamps();
     logger = logger.with_level(if matches.get_flag("verbose") {
@@ -248,6 +468,40 @@ pub fn main() -> Result<()> {
     debug!("pwd: {}", std::env::current_dir()?.as_path().display());
     let start = Instant::now();
     match matches.subcommand() {
+        Some(("check", subs)) => {
+            let g = if let Some(home) = subs.get_one::<PathBuf>("eoc") {
+                debug!("eoc: {}", home.display());
+                if !home.exists() {
+                    return Err(anyhow!(
+                        "The directory '{}' doesn't exist, run 'eoc sodg' first",
+                        home.display()
+                    ));
+                }
+                load_eoc(home.join("sodg").as_path())?
+            } else {
+                let bin = subs
+                    .get_one::<PathBuf>("file")
+                    .context("Path of .reo file is required")?;
+                debug!("bin: {}", bin.display());
+                if !bin.exists() {
+                    return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
+                }
+                Sodg::load(bin.as_path())?
+            };
+            let mut uni = Universe::from_graph(g);
+            register(&mut uni);
+            load_plugins(&mut uni, subs)?;
+            let missing = uni.missing_atoms()?;
+            for e in &missing {
+                if let ReoError::MissingAtom { v, loc, atom } = e {
+                    println!("{atom} at {loc} (ν{v})");
+                }
+            }
+            if !missing.is_empty() {
+                return Err(anyhow!("{} atom(s) are missing", missing.len()));
+            }
+            info!("All atoms are available");
+        }
         Some(("compile", subs)) => {
             let src = subs
                 .get_one::<PathBuf>("source")
@@ -277,6 +531,31 @@ pub fn main() -> Result<()> {
             info!("The SODG saved to '{}' ({size} bytes)", bin.display());
             print_metas(&mut g)?;
         }
+        Some(("decompile", subs)) => {
+            let bin = subs
+                .get_one::<PathBuf>("bin")
+                .context("Path of .reo file is required")
+                .unwrap();
+            debug!("bin: {}", bin.display());
+            if !bin.exists() {
+                return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
+            }
+            let mut g = Sodg::load(bin.as_path())?;
+            let script = decompile(&mut g)?;
+            match subs.get_one::<PathBuf>("source") {
+                Some(src) => {
+                    fs::write(src, &script)
+                        .context(anyhow!("Can't save to '{}'", src.display()))?;
+                    info!(
+                        "{} vertices decompiled to '{}' ({} bytes)",
+                        g.len(),
+                        src.display(),
+                        script.len()
+                    );
+                }
+                None => print!("{script}"),
+            }
+        }
         Some(("empty", subs)) => {
             let bin = subs
                 .get_one::<PathBuf>("target")
@@ -288,6 +567,37 @@ pub fn main() -> Result<()> {
             let size = g.save(bin)?;
             info!("Empty SODG saved to '{}' ({size} bytes)", bin.display());
         }
+        Some(("lint", subs)) => {
+            let bin = subs
+                .get_one::<PathBuf>("bin")
+                .context("Path of .reo file is required")
+                .unwrap();
+            debug!("bin: {}", bin.display());
+            if !bin.exists() {
+                return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
+            }
+            let mut g = Sodg::load(bin.as_path())?;
+            let found = lint(&mut g)?;
+            for f in &found {
+                let line = f.to_string();
+                match f.severity {
+                    Severity::Error => println!("{}", line.red()),
+                    Severity::Warning => println!("{}", line.yellow()),
+                }
+            }
+            let errors = found
+                .iter()
+                .filter(|f| f.severity == Severity::Error)
+                .count();
+            info!(
+                "{} vertices checked, {errors} errors and {} warnings found",
+                g.len(),
+                found.len() - errors
+            );
+            if errors > 0 {
+                return Err(anyhow!("Found {errors} error(s) in '{}'", bin.display()));
+            }
+        }
         Some(("merge", subs)) => {
             let target = subs
                 .get_one::<PathBuf>("target")
@@ -318,37 +628,180 @@ pub fn main() -> Result<()> {
             info!("The SODG saved to '{}' ({size} bytes)", target.display());
         }
         Some(("dataize", subs)) => {
+            let (g, object) = if let Some(home) = subs.get_one::<PathBuf>("eoc") {
+                debug!("eoc: {}", home.display());
+                if !home.exists() {
+                    return Err(anyhow!(
+                        "The directory '{}' doesn't exist, run 'eoc sodg' first",
+                        home.display()
+                    ));
+                }
+                // There is no .reo file with --eoc, so the only positional
+                // argument is the name of the object
+                if let Some(object) = subs.get_one::<String>("object") {
+                    return Err(anyhow!(
+                        "With --eoc, only the object name is expected, while '{object}' is given after it"
+                    ));
+                }
+                let object = subs
+                    .get_one::<PathBuf>("file")
+                    .context("Object name is required")?
+                    .to_string_lossy()
+                    .to_string();
+                debug!("object: {}", object);
+                let g = load_eoc(home.join("sodg").as_path())?;
+                info!(
+                    "Compiled and merged {} vertices in {:?}",
+                    g.len(),
+                    start.elapsed()
+                );
+                (g, object)
+            } else {
+                let bin = subs
+                    .get_one::<PathBuf>("file")
+                    .context("Path of .reo file is required")?;
+                debug!("bin: {}", bin.display());
+                if !bin.exists() {
+                    return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
+                }
+                let object = subs
+                    .get_one::<String>("object")
+                    .context("Object name is required")?
+                    .clone();
+                debug!("object: {}", object);
+                info!("Deserializing the binary file '{}'", bin.display());
+                let g = Sodg::load(bin.as_path())?;
+                info!(
+                    "Deserialized {} bytes in {:?}",
+                    fs::metadata(bin)?.len(),
+                    start.elapsed()
+                );
+                (g, object)
+            };
+            info!("Dataizing the '{object}' object...");
+            let mut uni = Universe::from_graph(g);
+            uni = with_limits(uni, subs);
+            if let Some(n) = subs.get_one::<usize>("gc-threshold") {
+                debug!("gc-threshold: {n}");
+                uni = uni.with_gc_threshold(*n);
+            }
+            if subs.get_flag("no-memo") {
+                debug!("memoization is turned off");
+                uni = uni.with_memo(false);
+            }
+            if let Some(home) = subs.get_one::<PathBuf>("snapshots") {
+                let format = subs
+                    .get_one::<String>("snapshot-format")
+                    .context("Snapshot format is required")?;
+                debug!("snapshots: {} ({format})", home.display());
+                let sink: Rc<RefCell<dyn SnapshotSink>> = match format.as_str() {
+                    "dot" => Rc::new(RefCell::new(DotSink::new(home))),
+                    "journal" => Rc::new(RefCell::new(JournalSink::new(
+                        home.join("journal.txt").as_path(),
+                    ))),
+                    _ => Rc::new(RefCell::new(HtmlSink::new(
+                        home.join("index.html").as_path(),
+                    ))),
+                };
+                uni = uni.with_snapshot_sink(sink);
+            }
+            let tracer = match subs.get_one::<PathBuf>("trace-out") {
+                Some(path) => {
+                    let format: TraceFormat = subs
+                        .get_one::<String>("trace-format")
+                        .context("Trace format is required")?
+                        .parse()?;
+                    debug!("trace-out: {} ({format:?})", path.display());
+                    let file =
+                        File::create(path).context(anyhow!("Can't create '{}'", path.display()))?;
+                    let t = Rc::new(RefCell::new(Tracer::new(
+                        Box::new(BufWriter::new(file)),
+                        format,
+                    )));
+                    uni = uni.with_hook(t.clone());
+                    Some((path, t))
+                }
+                None => None,
+            };
+            register(&mut uni);
+            load_plugins(&mut uni, subs)?;
+            let args: Vec<String> = subs
+                .get_many::<String>("args")
+                .map(|a| a.cloned().collect())
+                .unwrap_or_default();
+            debug!("args: {}", args.join(" "));
+            let array = ["org", "eolang", "array"]
+                .iter()
+                .try_fold(0, |v, a| uni.kid(v, a));
+            if args.is_empty() && array.is_none() {
+                debug!("There is no Φ.org.eolang.array, Φ.org.eolang.sys.args is not exposed");
+            } else {
+                let arr = uni.expose_args(&args)?;
+                info!(
+                    "{} argument(s) exposed as Φ.org.eolang.sys.args (ν{arr})",
+                    args.len()
+                );
+            }
+            let r = uni.dataize(format!("Φ.{}", object).as_str());
+            if subs.is_present("dump") {
+                let dump = subs.get_one::<PathBuf>("dump").unwrap();
+                debug!("dump: {}", dump.display());
+                let size = uni.dump(dump)?;
+                info!("Dump saved to '{}' ({size} bytes)", dump.display());
+            }
+            if let Some((path, t)) = tracer {
+                let total = t.borrow_mut().finish()?;
+                info!("{total} trace events saved to '{}'", path.display());
+            }
+            let d = r?;
+            info!(
+                "Dataization result, in {:?} is: {}",
+                start.elapsed(),
+                d.print()
+            );
+            let mode = subs
+                .get_one::<String>("as")
+                .context("Data type is required")?;
+            let t = if mode == "auto" {
+                let t = uni.data_type().unwrap_or(DataType::Hex);
+                info!("The type of the result is inferred as '{t}'");
+                if let Err(e) = t.decode(&d) {
+                    warn!("The result is not '{t}', printing it as 'hex': {e}");
+                    DataType::Hex
+                } else {
+                    t
+                }
+            } else {
+                mode.parse()?
+            };
+            if subs.get_flag("json") {
+                println!("{}", t.json(&d)?);
+            } else {
+                println!("{}", t.decode(&d)?);
+            }
+        }
+        Some(("debug", subs)) => {
             let bin = subs
                 .get_one::<PathBuf>("file")
-                .context("Path of .reo file is required")
-                .unwrap();
-            debug!("bin: {}", bin.display());
+                .context("Path of .reo file is required")?;
             if !bin.exists() {
                 return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
             }
             let object = subs
                 .get_one::<String>("object")
                 .context("Object name is required")?;
-            debug!("object: {}", object);
-            info!("Deserializing the binary file '{}'", bin.display());
             let g = Sodg::load(bin.as_path())?;
-            info!(
-                "Deserialized {} bytes in {:?}",
-                fs::metadata(bin)?.len(),
-                start.elapsed()
+            info!("Deserialized {} vertices from '{}'", g.len(), bin.display());
+            println!("Type 'help' to see all commands");
+            let debugger = Debugger::new(
+                Rc::new(RefCell::new(BufReader::new(io::stdin()))),
+                Rc::new(RefCell::new(io::stdout())),
             );
-            info!("Dataizing the '{object}' object...");
-            let mut uni = Universe::from_graph(g);
+            let mut uni = with_limits(Universe::from_graph(g), subs)
+                .with_hook(Rc::new(RefCell::new(debugger)));
             register(&mut uni);
-            let r = uni.dataize(format!("Φ.{}", object).as_str());
-            if subs.is_present("dump") {
-                let dump = subs.get_one::<PathBuf>("dump").unwrap();
-                debug!("dump: {}", dump.display());
-                let size = uni.dump(dump)?;
-                info!("Dump saved to '{}' ({size} bytes)", dump.display());
-            }
-            let ret = r?.print();
-            info!("Dataization result, in {:?} is: {ret}", start.elapsed());
+            load_plugins(&mut uni, subs)?;
+            let ret = uni.dataize(format!("Φ.{}", object).as_str())?.print();
             println!("{ret}");
         }
         Some(("dot", subs)) => {
@@ -387,6 +840,79 @@ pub fn main() -> Result<()> {
             let bytes = out.write(content.as_bytes())?;
             info!("DOT graph saved, {bytes} bytes in {:?}", start.elapsed());
         }
+        Some(("replay", subs)) => {
+            let journal = subs
+                .get_one::<PathBuf>("journal")
+                .context("Path of journal file is required")
+                .unwrap();
+            debug!("journal: {}", journal.display());
+            if !journal.exists() {
+                return Err(anyhow!("The file '{}' doesn't exist", journal.display()));
+            }
+            let snapshots = JournalSink::load(journal.as_path())?;
+            let step = subs
+                .get_one::<usize>("step")
+                .copied()
+                .unwrap_or(snapshots.len());
+            let Some(at) = snapshots.iter().find(|s| s.step == step) else {
+                return Err(anyhow!(
+                    "There is no step no.{step} in '{}', only {} steps are there",
+                    journal.display(),
+                    snapshots.len()
+                ));
+            };
+            info!("Step no.{step} at depth {}: {}", at.depth, at.msg);
+            let g = Graph::replay(&snapshots, step)?;
+            match subs.get_one::<PathBuf>("bin") {
+                Some(bin) => {
+                    let size = g.to_sodg()?.save(bin.as_path())?;
+                    info!(
+                        "The graph of {} vertices at step no.{step} saved to '{}' ({size} bytes)",
+                        g.vertices().count(),
+                        bin.display()
+                    );
+                }
+                None => print!("{}", g.to_dot(&at.fresh())?),
+            }
+        }
+        Some(("test", subs)) => {
+            let bin = subs
+                .get_one::<PathBuf>("file")
+                .context("Path of .reo file is required")
+                .unwrap();
+            debug!("bin: {}", bin.display());
+            if !bin.exists() {
+                return Err(anyhow!("The file '{}' doesn't exist", bin.display()));
+            }
+            let mut uni = with_limits(Universe::from_graph(Sodg::load(bin.as_path())?), subs);
+            register(&mut uni);
+            load_plugins(&mut uni, subs)?;
+            let names = uni.tests(subs.get_one::<String>("filter").unwrap())?;
+            let mut outcomes = vec![];
+            for name in names {
+                let o = uni.test(name.as_str());
+                let ms = o.time.as_millis();
+                match &o.failure {
+                    None => println!("{} {name} ({ms}ms)", "PASS".green()),
+                    Some(f) => println!("{} {name} ({ms}ms): {f}", "FAIL".red()),
+                }
+                outcomes.push(o);
+            }
+            let failed = outcomes.iter().filter(|o| !o.passed()).count();
+            println!(
+                "{} test(s), {} passed, {failed} failed",
+                outcomes.len(),
+                outcomes.len() - failed
+            );
+            if let Some(xml) = subs.get_one::<PathBuf>("junit") {
+                let suite = bin.file_name().unwrap().to_str().unwrap();
+                fs::write(xml, junit(suite, &outcomes))?;
+                info!("JUnit report saved to '{}'", xml.display());
+            }
+            if failed > 0 {
+                return Err(anyhow!("{failed} test(s) failed in '{}'", bin.display()));
+            }
+        }
         Some(("inspect", subs)) => {
             let bin = subs
                 .get_one::<PathBuf>("bin")
@@ -454,6 +980,116 @@ pub fn main() -> Result<()> {
     Ok(())
 }
 
+/// Compile all `.sodg` files found in the directory and merge them
+/// into one graph, the same way `compile`, `empty`, and `merge` do
+/// it one by one.
+fn load_eoc(sources: &Path) -> Result<Sodg> {
+    let mut g = Sodg::empty();
+    g.add(0)?;
+    let mut paths = vec![];
+    for f in glob(format!("{}/**/*.sodg", sources.display()).as_str())? {
+        let src = f?;
+        if src.is_dir() {
+            continue;
+        }
+        paths.push(src);
+    }
+    if paths.is_empty() {
+        return Err(anyhow!(
+            "There are no .sodg files in '{}'",
+            sources.display()
+        ));
+    }
+    paths.sort();
+    for src in paths {
+        let mut g2 = Sodg::empty();
+        let mut s = Script::from_str(fs::read_to_string(&src)?.as_str());
+        let ints = s
+            .deploy_to(&mut g2)
+            .context(format!("Failed with '{}'", src.display()))?;
+        debug!("Deployed {ints} instructions from {}", src.display());
+        let slice = g2.slice_some("ν0", |_, _, a| !a.starts_with('+'))?;
+        g.merge(&slice, 0, 0)
+            .context(format!("Can't merge '{}'", src.display()))?;
+        debug!("Merged {} vertices from {}", slice.len(), src.display());
+    }
+    Ok(g)
+}
+
+/// The `--eoc` argument, which points to the output directory of eoc.
+fn eoc_arg() -> Arg<'static> {
+    Arg::new("eoc")
+        .long("eoc")
+        .required(false)
+        .value_name("DIR")
+        .value_parser(PathValueParser {})
+        .help("Use the output directory of eoc, like .eoc, instead of a .reo file")
+        .takes_value(true)
+        .action(ArgAction::Set)
+}
+
+/// The `--plugin` argument, which may be repeated.
+fn plugin_arg() -> Arg<'static> {
+    Arg::new("plugin")
+        .long("plugin")
+        .required(false)
+        .value_parser(PathValueParser {})
+        .help("Shared library with extra atoms to load (may be repeated)")
+        .action(ArgAction::Append)
+}
+
+/// Load all shared libraries given with `--plugin`.
+fn load_plugins(uni: &mut Universe, subs: &ArgMatches) -> Result<()> {
+    if let Some(plugins) = subs.get_many::<PathBuf>("plugin") {
+        for p in plugins {
+            let total = uni.load_plugin(p)?;
+            info!("{total} atoms loaded from '{}'", p.display());
+        }
+    }
+    Ok(())
+}
+
+/// The `--max-*` arguments, which limit a dataization.
+fn limit_args() -> Vec<Arg<'static>> {
+    vec![
+        Arg::new("max-depth")
+            .long("max-depth")
+            .required(false)
+            .value_parser(value_parser!(usize))
+            .help("Maximum depth of recursion during dataization")
+            .action(ArgAction::Set),
+        Arg::new("max-steps")
+            .long("max-steps")
+            .required(false)
+            .value_parser(value_parser!(usize))
+            .help("Maximum number of applications during dataization")
+            .action(ArgAction::Set),
+        Arg::new("max-vertices")
+            .long("max-vertices")
+            .required(false)
+            .value_parser(value_parser!(usize))
+            .help("Maximum number of vertices created during dataization")
+            .action(ArgAction::Set),
+    ]
+}
+
+/// Apply the `--max-*` arguments to the Universe.
+fn with_limits(mut uni: Universe, subs: &ArgMatches) -> Universe {
+    if let Some(max) = subs.get_one::<usize>("max-depth") {
+        debug!("max-depth: {max}");
+        uni = uni.with_max_depth(*max);
+    }
+    if let Some(max) = subs.get_one::<usize>("max-steps") {
+        debug!("max-steps: {max}");
+        uni = uni.with_max_steps(*max);
+    }
+    if let Some(max) = subs.get_one::<usize>("max-vertices") {
+        debug!("max-vertices: {max}");
+        uni = uni.with_max_vertices(*max);
+    }
+    uni
+}
+
 fn print_metas(g: &mut Sodg) -> Result<()> {
     match g.kids(0) {
         Ok(vec) => {
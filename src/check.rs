@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::{ReoError, Universe};
+use anyhow::Result;
+use std::collections::{HashMap, VecDeque};
+
+impl Universe {
+    /// Find all `λ` in the graph, which refer to atoms that are not
+    /// registered, without dataizing anything. Each of them is returned
+    /// as [`ReoError::MissingAtom`], where the `loc` is the shortest path
+    /// from `ν0` to the vertex with the `λ`, like `Φ.org.eolang.foo`:
+    ///
+    /// ```
+    /// use reo::{ReoError, Universe};
+    /// use sodg::Hex;
+    /// let mut uni = Universe::empty();
+    /// let root = uni.add()?;
+    /// let v1 = uni.add()?;
+    /// uni.bind(root, v1, "foo")?;
+    /// let v2 = uni.add()?;
+    /// uni.put(v2, Hex::from_str_bytes("absent"))?;
+    /// uni.bind(v1, v2, "λ")?;
+    /// assert_eq!(
+    ///     vec![ReoError::MissingAtom { v: 1, loc: "Φ.foo".to_string(), atom: "absent".to_string() }],
+    ///     uni.missing_atoms()?
+    /// );
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    ///
+    /// The vertices that are not reachable from `ν0` have their IDs
+    /// as `loc`, like `ν42`. If the data of a `λ` is not a valid UTF-8
+    /// string, the atom is reported as missing, with its bytes as `atom`,
+    /// like `FF-FE`.
+    pub fn missing_atoms(&mut self) -> Result<Vec<ReoError>> {
+        let paths = self.paths();
+        let mut ids = self.g.ids();
+        ids.sort_unstable();
+        let mut missing = vec![];
+        for v in ids {
+            let Some(lv) = self.g.kid(v, "λ") else {
+                continue;
+            };
+            let data = self.g.data(lv)?;
+            let atom = data.to_utf8().unwrap_or_else(|_| data.print());
+            if self.atoms.contains_key(atom.as_str()) {
+                continue;
+            }
+            missing.push(ReoError::MissingAtom {
+                v,
+                loc: paths.get(&v).cloned().unwrap_or(format!("ν{v}")),
+                atom,
+            });
+        }
+        Ok(missing)
+    }
+
+    /// The shortest paths from `ν0` to all vertices reachable from it,
+    /// not going through `ρ`, `σ`, `π`, `ψ`, `λ`, `Δ`, and metas.
+    pub(crate) fn paths(&self) -> HashMap<u32, String> {
+        let mut paths = HashMap::new();
+        if self.g.kids(0).is_err() {
+            return paths;
+        }
+        paths.insert(0, "Φ".to_string());
+        let mut todo = VecDeque::from([0]);
+        while let Some(v) = todo.pop_front() {
+            let mut kids = self.g.kids(v).unwrap_or_default();
+            kids.sort();
+            for (a, k) in kids {
+                if paths.contains_key(&k)
+                    || ["ρ", "σ", "π", "ψ", "λ", "Δ"].contains(&a.as_str())
+                    || a.starts_with('+')
+                {
+                    continue;
+                }
+                let path = format!("{}.{a}", paths[&v]);
+                paths.insert(k, path);
+                todo.push_back(k);
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+#[test]
+fn finds_nothing_when_all_atoms_registered() -> Result<()> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let v1 = uni.add()?;
+    uni.bind(root, v1, "foo")?;
+    let v2 = uni.add()?;
+    uni.put(v2, Hex::from_str_bytes("inc"))?;
+    uni.bind(v1, v2, "λ")?;
+    uni.register("inc", |_: &mut Universe, v| Ok(v));
+    assert!(uni.missing_atoms()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn finds_missing_atoms_with_paths() -> Result<()> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let x = uni.add()?;
+    uni.bind(org, x, "x")?;
+    uni.bind(x, org, "ρ")?;
+    let lost = uni.add()?;
+    for (v, atom) in [(x, "org.x"), (lost, "lost")] {
+        let lv = uni.add()?;
+        uni.put(lv, Hex::from_str_bytes(atom))?;
+        uni.bind(v, lv, "λ")?;
+    }
+    let missing = uni.missing_atoms()?;
+    assert_eq!(2, missing.len());
+    assert_eq!(missing[0].locator(), "Φ.org.x");
+    assert_eq!(missing[1].locator(), format!("ν{lost}"));
+    assert!(missing[1].to_string().contains("'lost'"));
+    Ok(())
+}
+
+#[test]
+fn reports_atoms_with_broken_names() -> Result<()> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    for (a, bytes) in [("foo", vec![0xFF, 0xFE]), ("bar", b"lost".to_vec())] {
+        let v = uni.add()?;
+        uni.bind(root, v, a)?;
+        let lv = uni.add()?;
+        uni.put(lv, Hex::from_vec(bytes))?;
+        uni.bind(v, lv, "λ")?;
+    }
+    let missing = uni.missing_atoms()?;
+    assert_eq!(2, missing.len());
+    assert!(missing[0].to_string().contains("'FF-FE'"), "{}", missing[0]);
+    assert_eq!(missing[1].locator(), "Φ.bar");
+    Ok(())
+}
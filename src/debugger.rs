@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::{Event, Hook, Universe};
+use anyhow::{anyhow, Context, Result};
+use std::cell::RefCell;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
+
+/// An interactive debugger of dataization, which pauses at the
+/// steps of it and asks what to do next:
+///
+/// ```
+/// use reo::{Debugger, Universe};
+/// use sodg::Hex;
+/// use std::cell::RefCell;
+/// use std::io::Cursor;
+/// use std::rc::Rc;
+/// let input = Rc::new(RefCell::new(Cursor::new("step\ncontinue\n")));
+/// let output = Rc::new(RefCell::new(vec![]));
+/// let mut uni = Universe::empty()
+///     .with_hook(Rc::new(RefCell::new(Debugger::new(input, output.clone()))));
+/// let root = uni.add()?;
+/// let v1 = uni.add()?;
+/// uni.bind(root, v1, "foo")?;
+/// let v2 = uni.add()?;
+/// uni.bind(v1, v2, "φ")?;
+/// let v3 = uni.add()?;
+/// uni.bind(v2, v3, "Δ")?;
+/// uni.put(v3, Hex::from(42))?;
+/// assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+/// assert!(String::from_utf8(output.borrow().clone())?.contains("(reo)"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// When the input is over, the dataization continues without pauses.
+pub struct Debugger {
+    /// Where the commands come from.
+    input: Rc<RefCell<dyn BufRead>>,
+    /// Where the debugger prints to.
+    output: Rc<RefCell<dyn Write>>,
+    /// When to pause next time.
+    mode: Mode,
+    /// All breakpoints.
+    breaks: Vec<Breakpoint>,
+}
+
+/// When the debugger pauses.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// At every step.
+    Step,
+    /// At the step that is not deeper than this.
+    Next(usize),
+    /// Only at breakpoints.
+    Continue,
+}
+
+/// Where the debugger pauses, no matter what.
+#[derive(Clone, PartialEq, Eq)]
+enum Breakpoint {
+    /// Entering the vertex with this ID.
+    Vertex(u32),
+    /// Entering any vertex in order to get this attribute.
+    Attribute(String),
+}
+
+const HELP: &str = "Commands:
+  step (s)          pause at the next step
+  next (n)          pause at the next step, which is not deeper than this one
+  continue (c)      pause only at breakpoints
+  break (b) νN      pause when entering the vertex νN
+  break (b) A       pause when entering any vertex to get the attribute A
+  delete (d)        delete all breakpoints
+  print (p) LOC     find the locator and print the vertex
+                    (careful: it may call atoms, like a dataization does)
+  show              print the current vertex again
+  quit (q)          stop the dataization
+  help (h)          print this help";
+
+impl Debugger {
+    /// Make a debugger that reads commands from the input and prints
+    /// to the output. It pauses at the first step.
+    pub fn new(input: Rc<RefCell<dyn BufRead>>, output: Rc<RefCell<dyn Write>>) -> Self {
+        Debugger {
+            input,
+            output,
+            mode: Mode::Step,
+            breaks: vec![],
+        }
+    }
+
+    /// Should it pause at this event?
+    fn pauses(&self, event: &Event) -> bool {
+        if let Event::Enter { v, loc, .. } = event {
+            let hit = self.breaks.iter().any(|b| match b {
+                Breakpoint::Vertex(bv) => bv == v,
+                Breakpoint::Attribute(a) => loc.ends_with(format!(".{a}").as_str()),
+            });
+            if hit {
+                return true;
+            }
+        }
+        match self.mode {
+            Mode::Step => true,
+            Mode::Next(d) => Self::depth(event) <= d,
+            Mode::Continue => false,
+        }
+    }
+
+    fn depth(event: &Event) -> usize {
+        match event {
+            Event::Enter { depth, .. } | Event::Exit { depth, .. } => *depth,
+        }
+    }
+
+    fn say(&self, text: &str) -> Result<()> {
+        let mut out = self.output.borrow_mut();
+        writeln!(out, "{text}")?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Print the event and the vertex it happened at.
+    fn show(&self, uni: &mut Universe, event: &Event) -> Result<()> {
+        match event {
+            Event::Enter {
+                v, loc, depth, msg, ..
+            } => {
+                self.say(format!("[{depth}] enter ν{v} for {loc}: {msg}").as_str())?;
+                self.show_v(uni, *v)
+            }
+            Event::Exit { v, depth, msg, .. } => {
+                self.say(format!("[{depth}] exit ν{v}: {msg}").as_str())?;
+                self.show_v(uni, *v)
+            }
+        }
+    }
+
+    /// Print the kids of the vertex, the way `reo inspect` does.
+    fn show_v(&self, uni: &mut Universe, v: u32) -> Result<()> {
+        let mut kids = uni.kids(v)?;
+        kids.sort_by(|a, b| a.0.cmp(&b.0));
+        for (a, to) in kids {
+            let mut line = format!("  {a} -> ν{to}");
+            if a == "Δ" {
+                line.push_str(format!(" {}", uni.data(to)?).as_str());
+            }
+            if a == "λ" {
+                line.push_str(format!(" {}", uni.data(to)?.to_utf8()?).as_str());
+            }
+            self.say(line.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// Find the locator in the Universe and print the vertex found.
+    ///
+    /// The search goes through the relay, just like a dataization,
+    /// so it may call atoms, together with their side effects.
+    fn print(&self, uni: &mut Universe, loc: &str) -> Result<()> {
+        let depth = uni.depth;
+        let stack = uni.stack.len();
+        let r = uni.find(loc);
+        uni.depth = depth;
+        uni.stack.truncate(stack);
+        let v = r?;
+        self.say(format!("{loc} is ν{v}").as_str())?;
+        self.show_v(uni, v)
+    }
+
+    /// Read the next command, or `None` if the input is over.
+    fn command(&self) -> Result<Option<String>> {
+        {
+            let mut out = self.output.borrow_mut();
+            write!(out, "(reo) ")?;
+            out.flush()?;
+        }
+        let mut line = String::new();
+        let bytes = self
+            .input
+            .borrow_mut()
+            .read_line(&mut line)
+            .context("Failed to read a command")?;
+        if bytes == 0 {
+            self.say("")?;
+            return Ok(None);
+        }
+        Ok(Some(line.trim().to_string()))
+    }
+}
+
+impl Hook for Debugger {
+    fn on(&mut self, uni: &mut Universe, event: &Event) -> Result<()> {
+        if !self.pauses(event) {
+            return Ok(());
+        }
+        self.show(uni, event)?;
+        loop {
+            let Some(line) = self.command()? else {
+                self.mode = Mode::Continue;
+                self.breaks.clear();
+                return Ok(());
+            };
+            let (cmd, arg) = match line.split_once(' ') {
+                Some((c, a)) => (c, a.trim()),
+                None => (line.as_str(), ""),
+            };
+            match cmd {
+                "step" | "s" => {
+                    self.mode = Mode::Step;
+                    return Ok(());
+                }
+                "next" | "n" => {
+                    self.mode = Mode::Next(Self::depth(event));
+                    return Ok(());
+                }
+                "continue" | "c" => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                "break" | "b" => {
+                    let b = match arg.strip_prefix('ν') {
+                        Some(num) => match num.parse() {
+                            Ok(v) => Breakpoint::Vertex(v),
+                            Err(_) => Breakpoint::Attribute(arg.to_string()),
+                        },
+                        None => Breakpoint::Attribute(arg.to_string()),
+                    };
+                    if arg.is_empty() {
+                        self.say("A vertex or an attribute is expected")?;
+                    } else {
+                        self.breaks.push(b);
+                        self.say(
+                            format!("Breakpoint #{} set at {arg}", self.breaks.len()).as_str(),
+                        )?;
+                    }
+                }
+                "delete" | "d" => {
+                    self.say(format!("{} breakpoints deleted", self.breaks.len()).as_str())?;
+                    self.breaks.clear();
+                }
+                "print" | "p" => {
+                    if let Err(e) = self.print(uni, arg) {
+                        self.say(format!("{e:#}").as_str())?;
+                    }
+                }
+                "show" => self.show(uni, event)?,
+                "quit" | "q" => {
+                    return Err(anyhow!("The dataization is stopped by the debugger"));
+                }
+                "help" | "h" => self.say(HELP)?,
+                "" => {}
+                _ => self.say(format!("Unknown command '{cmd}', try 'help'").as_str())?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+#[cfg(test)]
+use std::io::Cursor;
+
+/// Make a Universe where `foo` is a decorator of `bar`, which is `42`,
+/// debugged with the given commands.
+#[cfg(test)]
+fn debugged(commands: &str) -> Result<(Universe, Rc<RefCell<Vec<u8>>>)> {
+    let input = Rc::new(RefCell::new(Cursor::new(commands.to_string())));
+    let output = Rc::new(RefCell::new(vec![]));
+    let mut uni =
+        Universe::empty().with_hook(Rc::new(RefCell::new(Debugger::new(input, output.clone()))));
+    let root = uni.add()?;
+    let bar = uni.add()?;
+    uni.bind(root, bar, "bar")?;
+    let d = uni.add()?;
+    uni.bind(bar, d, "Δ")?;
+    uni.put(d, Hex::from(42))?;
+    let foo = uni.add()?;
+    uni.bind(root, foo, "foo")?;
+    let phi = uni.add()?;
+    uni.bind(foo, phi, "φ")?;
+    uni.bind(phi, bar, "ε")?;
+    Ok((uni, output))
+}
+
+#[test]
+fn steps_through() -> Result<()> {
+    let (mut uni, output) = debugged("s\ns\ns\nc\n")?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let text = String::from_utf8(output.borrow().clone())?;
+    assert_eq!(4, text.matches("(reo) ").count(), "{text}");
+    assert!(text.contains("enter ν3 for ν3.Δ"), "{text}");
+    assert!(text.contains("φ -> ν4"), "{text}");
+    Ok(())
+}
+
+#[test]
+fn stops_at_breakpoint() -> Result<()> {
+    let (mut uni, output) = debugged("b ν1\nc\nc\n")?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let text = String::from_utf8(output.borrow().clone())?;
+    assert!(text.contains("Breakpoint #1 set at ν1"), "{text}");
+    assert!(text.contains("enter ν1 for"), "{text}");
+    assert!(text.contains("Δ -> ν2 00-00-00-00-00-00-00-2A"), "{text}");
+    Ok(())
+}
+
+#[test]
+fn prints_locator() -> Result<()> {
+    let (mut uni, output) = debugged("p Φ.bar\np Φ.absent\nc\n")?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let text = String::from_utf8(output.borrow().clone())?;
+    assert!(text.contains("Φ.bar is ν1"), "{text}");
+    assert!(text.contains("Failed to find Φ.absent"), "{text}");
+    Ok(())
+}
+
+#[test]
+fn quits() -> Result<()> {
+    let (mut uni, _) = debugged("q\n")?;
+    assert!(uni.dataize("Φ.foo").is_err());
+    Ok(())
+}
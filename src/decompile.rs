@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use anyhow::Result;
+use sodg::Sodg;
+use std::collections::HashMap;
+
+/// Turn the graph back into a script of `ADD`, `BIND` and `PUT`
+/// instructions, which [`sodg::Script`] understands:
+///
+/// ```
+/// use reo::decompile;
+/// use sodg::{Script, Sodg};
+/// let mut g = Sodg::empty();
+/// Script::from_str("ADD(0); ADD($ν1); BIND(ν0, $ν1, foo);").deploy_to(&mut g)?;
+/// assert_eq!("ADD(ν0);\n\nADD($ν1);\nBIND(ν0, $ν1, foo);\n", decompile(&mut g)?);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// The vertices are visited from `ν0`, in the order of their attributes,
+/// and then the vertices that are not reachable from it, in the order of
+/// their IDs. All of them, except `ν0`, are named `$ν1`, `$ν2`, and so on,
+/// in the order they are visited. Thus, the script is the same for the
+/// graphs that differ only in the IDs of their vertices. The data of
+/// the `+` attributes of `ν0`, which are the metas, is printed in comments.
+pub fn decompile(g: &mut Sodg) -> Result<String> {
+    let mut ids = g.ids();
+    ids.sort_unstable();
+    let shift = usize::from(ids.first() != Some(&0));
+    let mut names: HashMap<u32, String> = HashMap::new();
+    let mut order = vec![];
+    let mut lines = vec![];
+    for start in ids {
+        if names.contains_key(&start) {
+            continue;
+        }
+        let name = if start == 0 {
+            "ν0".to_string()
+        } else {
+            format!("$ν{}", names.len() + shift)
+        };
+        lines.push(format!("ADD({name});"));
+        names.insert(start, name);
+        put(g, start, &names, &mut lines)?;
+        order.push(start);
+        let mut pos = order.len() - 1;
+        while pos < order.len() {
+            let v = order[pos];
+            pos += 1;
+            let mut kids = g.kids(v)?;
+            kids.sort();
+            if !kids.is_empty() {
+                lines.push(String::new());
+            }
+            for (a, k) in kids {
+                if !names.contains_key(&k) {
+                    if v == 0 && a.starts_with('+') {
+                        if let Ok(txt) = g.data(k)?.to_utf8() {
+                            lines.push(format!("# {a}: {}", txt.replace('\n', " ")));
+                        }
+                    }
+                    let name = format!("$ν{}", names.len() + shift);
+                    lines.push(format!("ADD({name});"));
+                    names.insert(k, name);
+                    put(g, k, &names, &mut lines)?;
+                    order.push(k);
+                }
+                lines.push(format!("BIND({}, {}, {a});", names[&v], names[&k]));
+            }
+        }
+        lines.push(String::new());
+    }
+    Ok(lines
+        .join("\n")
+        .replace("\n\n\n", "\n\n")
+        .trim_end()
+        .to_string()
+        + "\n")
+}
+
+/// Print the `PUT` instruction for the vertex, if it has data.
+fn put(g: &mut Sodg, v: u32, names: &HashMap<u32, String>, lines: &mut Vec<String>) -> Result<()> {
+    let d = g.data(v)?;
+    if !d.is_empty() {
+        lines.push(format!("PUT({}, {});", names[&v], d.print()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+use sodg::Script;
+
+#[cfg(test)]
+fn deployed(script: &str) -> Result<Sodg> {
+    let mut g = Sodg::empty();
+    Script::from_str(script).deploy_to(&mut g)?;
+    Ok(g)
+}
+
+#[test]
+fn decompiles_with_metas() -> Result<()> {
+    let mut g = deployed(
+        "
+        ADD(0);
+        ADD($ν1); BIND(ν0, $ν1, +package); PUT($ν1, 6F-72-67);
+        ADD($ν2); BIND(ν0, $ν2, foo);
+        ADD($ν3); BIND($ν2, $ν3, Δ); PUT($ν3, 00-2A);
+        BIND($ν2, ν0, ρ);
+        ",
+    )?;
+    assert_eq!(
+        "ADD(ν0);\n\n# +package: org\nADD($ν1);\nPUT($ν1, 6F-72-67);\nBIND(ν0, $ν1, +package);\nADD($ν2);\nBIND(ν0, $ν2, foo);\n\nADD($ν3);\nPUT($ν3, 00-2A);\nBIND($ν2, $ν3, Δ);\nBIND($ν2, ν0, ρ);\n",
+        decompile(&mut g)?
+    );
+    Ok(())
+}
+
+#[test]
+fn round_trips() -> Result<()> {
+    let mut g = deployed(
+        "
+        ADD(0);
+        ADD(7); BIND(ν0, ν7, bar);
+        ADD(3); BIND(ν7, ν3, Δ); PUT(ν3, 00-00-00-00-00-00-00-2A);
+        ADD(5); BIND(ν0, ν5, foo); BIND(ν5, ν7, π);
+        ADD(9); ADD(11); BIND(ν9, ν11, lost);
+        ",
+    )?;
+    let first = decompile(&mut g)?;
+    let second = decompile(&mut deployed(first.as_str())?)?;
+    assert_eq!(first, second);
+    assert!(first.contains("BIND($ν4, $ν5, lost);"), "{first}");
+    Ok(())
+}
@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::{Budget, ReoError};
+use std::fmt;
+
+impl ReoError {
+    /// The ID of the vertex where the error happened.
+    pub fn vertex(&self) -> u32 {
+        match self {
+            ReoError::AttributeNotFound { v, .. }
+            | ReoError::MissingAtom { v, .. }
+            | ReoError::RecursionLimit { v, .. }
+            | ReoError::NoData { v, .. }
+            | ReoError::TieFailure { v, .. }
+            | ReoError::AtomFailure { v, .. } => *v,
+        }
+    }
+
+    /// The locator that was being resolved when the error happened.
+    pub fn locator(&self) -> &str {
+        match self {
+            ReoError::AttributeNotFound { loc, .. }
+            | ReoError::MissingAtom { loc, .. }
+            | ReoError::RecursionLimit { loc, .. }
+            | ReoError::NoData { loc, .. }
+            | ReoError::TieFailure { loc, .. }
+            | ReoError::AtomFailure { loc, .. } => loc.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Budget::Depth => "depth",
+            Budget::Steps => "steps",
+            Budget::Vertices => "vertices",
+        })
+    }
+}
+
+impl fmt::Display for ReoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReoError::AttributeNotFound { loc, .. } => {
+                write!(f, "There is no way to get {loc}")
+            }
+            ReoError::MissingAtom { v, loc, atom } => {
+                write!(f, "Can't find atom '{atom}' of ν{v}, while getting {loc}")
+            }
+            ReoError::RecursionLimit {
+                loc,
+                budget,
+                used,
+                max,
+                ..
+            } => {
+                match budget {
+                    Budget::Depth => write!(f, "The recursion is too deep ({used} levels)")?,
+                    Budget::Steps => write!(f, "Too many applications ({used})")?,
+                    Budget::Vertices => write!(f, "Too many vertices created ({used})")?,
+                }
+                write!(
+                    f,
+                    " at {loc}, the budget of {budget} is exhausted (--max-{budget} is {max})"
+                )
+            }
+            ReoError::NoData { v, loc } => {
+                write!(f, "There is no data in ν{v}, while getting {loc}")
+            }
+            ReoError::TieFailure { loc, .. } => write!(f, "Can't tie to {loc}"),
+            ReoError::AtomFailure {
+                v,
+                loc,
+                atom,
+                cause,
+                ..
+            } => write!(
+                f,
+                "The atom '{atom}' of ν{v} failed, while getting {loc}: {cause}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReoError {}
+
+#[test]
+fn prints_exhausted_budget() {
+    let e = ReoError::RecursionLimit {
+        v: 7,
+        loc: "ν7.foo".to_string(),
+        budget: Budget::Steps,
+        used: 11,
+        max: 10,
+    };
+    assert_eq!(
+        "Too many applications (11) at ν7.foo, the budget of steps is exhausted (--max-steps is 10)",
+        e.to_string()
+    );
+    assert_eq!(7, e.vertex());
+    assert_eq!("ν7.foo", e.locator());
+}
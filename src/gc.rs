@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::{Change, Universe};
+use anyhow::{anyhow, Result};
+use log::debug;
+use sodg::Sodg;
+use std::collections::HashSet;
+
+impl Universe {
+    /// Delete all vertices that are not reachable from `ν0`, neither
+    /// from the data memoized. Returns the number of vertices deleted.
+    ///
+    /// It may only run between dataizations, since the vertices that
+    /// atoms hold in their local variables are not known to it, so
+    /// a call from inside of an atom is refused with an error.
+    ///
+    /// It also runs automatically, see [`Universe::with_gc_threshold`].
+    pub fn gc(&mut self) -> Result<usize> {
+        if self.nesting > 0 {
+            return Err(anyhow!(
+                "The GC can't run in the middle of a dataization, while {} calls of find() are running",
+                self.nesting
+            ));
+        }
+        let before = self.g.len();
+        if self.g.kids(0).is_err() {
+            return Ok(0);
+        }
+        let mut live = HashSet::new();
+        mark(&self.g, vec![0], &mut live);
+        if let Some(memo) = self.memo.as_mut() {
+            loop {
+                let extra: Vec<u32> = memo
+                    .iter()
+                    .filter(|(k, v)| live.contains(*k) && !live.contains(*v))
+                    .map(|(_, v)| *v)
+                    .collect();
+                if extra.is_empty() {
+                    break;
+                }
+                mark(&self.g, extra, &mut live);
+            }
+            memo.retain(|k, _| live.contains(k));
+        }
+        if live.len() == before {
+            return Ok(0);
+        }
+        let mut g = self.g.slice("ν0")?;
+        let mut extra: Vec<u32> = live.into_iter().filter(|v| g.kids(*v).is_err()).collect();
+        extra.sort_unstable();
+        for v in &extra {
+            g.add(*v)?;
+            let d = self.g.data(*v)?;
+            if !d.is_empty() {
+                g.put(*v, &d)?;
+            }
+        }
+        for v in &extra {
+            for (a, k) in self.g.kids(*v)? {
+                g.bind(*v, k, a.as_str())?;
+            }
+        }
+        let freed = before - g.len();
+        if let Some(rec) = self.snapshots.as_mut() {
+            let mut gone: Vec<u32> = self
+                .g
+                .ids()
+                .into_iter()
+                .filter(|v| g.kids(*v).is_err())
+                .collect();
+            gone.sort_unstable();
+            for v in gone {
+                rec.note(Change::Drop(v));
+            }
+        }
+        self.g = g;
+        debug!("#gc: {freed} vertices deleted, {} left", self.g.len());
+        Ok(freed)
+    }
+
+    /// Run the GC, if the graph has grown enough since the last time.
+    pub(crate) fn gc_if_needed(&mut self) -> Result<()> {
+        if self.gc_threshold == 0 || self.g.len() < self.gc_next {
+            return Ok(());
+        }
+        self.gc()?;
+        self.gc_next = self.g.len() + self.gc_threshold;
+        Ok(())
+    }
+}
+
+/// Mark all vertices reachable from the roots as live.
+fn mark(g: &Sodg, roots: Vec<u32>, live: &mut HashSet<u32>) {
+    let mut todo = roots;
+    while let Some(v) = todo.pop() {
+        if !live.insert(v) {
+            continue;
+        }
+        if let Ok(kids) = g.kids(v) {
+            todo.extend(kids.into_iter().map(|(_, k)| k));
+        } else {
+            live.remove(&v);
+        }
+    }
+}
@@ -9,43 +9,246 @@
 //! use sodg::Hex;
 //! use reo::Universe;
 //! let mut uni = Universe::empty();
-//! let root = uni.add();
-//! let v1 = uni.add();
-//! uni.bind(root, v1, "foo");
-//! let v2 = uni.add();
-//! uni.bind(v1, v2, "Δ");
-//! uni.put(v2, Hex::from(42));
-//! assert_eq!(42, uni.dataize("Φ.foo").unwrap().to_i64().unwrap());
+//! let root = uni.add()?;
+//! let v1 = uni.add()?;
+//! uni.bind(root, v1, "foo")?;
+//! let v2 = uni.add()?;
+//! uni.bind(v1, v2, "Δ")?;
+//! uni.put(v2, Hex::from(42))?;
+//! assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+//! # Ok::<(), anyhow::Error>(())
 //! ```
 
 #![doc(html_root_url = "https://docs.rs/reo/0.0.0")]
 #![deny(warnings)]
 
+mod args;
+mod check;
+mod debugger;
+mod decompile;
+mod error;
+mod gc;
+mod lint;
 pub mod org;
+pub mod plugin;
+mod runner;
 mod scripts;
+mod snapshots;
+mod tracer;
+mod typed;
 mod universe;
 
+pub use debugger::Debugger;
+pub use decompile::decompile;
+pub use lint::{lint, Finding, Rule, Severity, RULES};
+pub use runner::{junit, Outcome};
+pub use snapshots::{
+    Change, DotSink, Graph, HtmlSink, JournalSink, MemorySink, Snapshot, SnapshotSink, Vertex,
+};
+pub use tracer::{TraceFormat, Tracer};
+pub use typed::DataType;
+
 use anyhow::Result;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::rc::Rc;
 
 /// A single atom to be attached to a vertex.
 ///
-/// It is a function that is called by [`Universe`] when it's impossible
+/// It is called by [`Universe`] when it's impossible
 /// to get data from a vertex. The first argument provided is
 /// the [`Universe`] itself, while the second one is the ID of the
 /// vertex where the dataization is standing at the moment.
-pub type Atom = fn(&mut Universe, v: u32) -> Result<u32>;
+///
+/// Any function or closure with this signature is an atom, so it
+/// may capture the services of the host, like a generator of
+/// random numbers or a buffer. Atoms may be called recursively,
+/// that's why they take `&self`: keep the state in a [`RefCell`]:
+///
+/// ```
+/// use reo::Universe;
+/// use sodg::Hex;
+/// use std::cell::RefCell;
+/// let mut uni = Universe::empty();
+/// let root = uni.add()?;
+/// let v1 = uni.add()?;
+/// uni.bind(root, v1, "foo")?;
+/// let v2 = uni.add()?;
+/// uni.bind(v1, v2, "λ")?;
+/// uni.put(v2, Hex::from_str_bytes("next"))?;
+/// let counter = RefCell::new(41);
+/// uni.register("next", move |uni: &mut Universe, _: u32| {
+///     *counter.borrow_mut() += 1;
+///     let v = uni.add()?;
+///     let d = uni.add()?;
+///     uni.bind(v, d, "Δ")?;
+///     uni.put(d, Hex::from(*counter.borrow()))?;
+///     Ok(v)
+/// });
+/// assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+/// assert_eq!(43, uni.dataize("Φ.foo")?.to_i64()?);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub trait Atom {
+    /// Calculate the atom at the vertex `v` and return the vertex
+    /// with the result.
+    fn call(&self, uni: &mut Universe, v: u32) -> Result<u32>;
+
+    /// The name of the atom, which is used in logs. It's the name of
+    /// the Rust type, unless overridden.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+impl<F: Fn(&mut Universe, u32) -> Result<u32>> Atom for F {
+    fn call(&self, uni: &mut Universe, v: u32) -> Result<u32> {
+        self(uni, v)
+    }
+}
+
+/// A point of dataization, where [`Universe`] calls its hooks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The operation `op`, like `dd` or `apply`, is going to process
+    /// the vertex `v` with the given `ψ` (or zero), in order to get `loc`.
+    Enter {
+        op: &'static str,
+        v: u32,
+        psi: u32,
+        loc: String,
+        depth: usize,
+        msg: String,
+    },
+    /// The operation `op` is done with the vertex `v`, which
+    /// was entered at the same `depth`, and `result` is what it found.
+    Exit {
+        op: &'static str,
+        v: u32,
+        result: u32,
+        depth: usize,
+        msg: String,
+    },
+}
+
+/// Something that watches a dataization, see [`Universe::with_hook`].
+///
+/// While a hook is called, no other hooks are called, even if it
+/// dataizes something in the Universe.
+pub trait Hook {
+    /// The event just happened. If an error is returned,
+    /// the dataization stops with it.
+    fn on(&mut self, uni: &mut Universe, event: &Event) -> Result<()>;
+}
+
+/// One of the limits of a dataization, see
+/// [`Universe::with_max_depth`], [`Universe::with_max_steps`],
+/// and [`Universe::with_max_vertices`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Budget {
+    /// The depth of recursion.
+    Depth,
+    /// The number of `apply` calls.
+    Steps,
+    /// The number of vertices created.
+    Vertices,
+}
+
+/// An error that may happen in a [`Universe`] during dataization.
+///
+/// Each variant carries the ID of the vertex where the problem was
+/// found and the locator that was being resolved there. It is
+/// returned wrapped into [`anyhow::Error`], so use `downcast_ref` to
+/// get it back:
+///
+/// ```
+/// use reo::{ReoError, Universe};
+/// let mut uni = Universe::empty();
+/// uni.add()?;
+/// let err = uni.dataize("Φ.foo").unwrap_err();
+/// assert!(matches!(
+///     err.downcast_ref::<ReoError>(),
+///     Some(ReoError::AttributeNotFound { v: 0, .. })
+/// ));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReoError {
+    /// There is no way to get the attribute from the vertex.
+    AttributeNotFound { v: u32, loc: String },
+    /// The `λ` of the vertex refers to an atom that is not registered.
+    MissingAtom { v: u32, loc: String, atom: String },
+    /// One of the budgets of the dataization is exhausted.
+    RecursionLimit {
+        v: u32,
+        loc: String,
+        budget: Budget,
+        used: usize,
+        max: usize,
+    },
+    /// The vertex has no data.
+    NoData { v: u32, loc: String },
+    /// The attribute can't be tied to the vertex, while applying.
+    TieFailure { v: u32, loc: String },
+    /// The atom of the vertex returned an error.
+    AtomFailure {
+        v: u32,
+        loc: String,
+        atom: String,
+        cause: String,
+    },
+}
 
 /// A Universe.
+#[derive(Clone)]
 pub struct Universe {
     /// The graph.
     g: Sodg,
     /// All known atoms.
-    atoms: HashMap<String, Atom>,
+    atoms: HashMap<String, Rc<dyn Atom>>,
     /// The depth of recursion of the current dataization.
     depth: usize,
-    /// Location of snapshots directory.
-    snapshots: Option<String>,
+    /// How many times `apply` was called in the current dataization.
+    steps: usize,
+    /// How many vertices were created in the current dataization.
+    vertices: usize,
+    /// How many calls to `find` are running now, one inside another.
+    nesting: usize,
+    /// The maximum depth of recursion allowed.
+    max_depth: usize,
+    /// The maximum number of `apply` calls allowed in one dataization.
+    max_steps: usize,
+    /// The maximum number of vertices one dataization may create.
+    max_vertices: usize,
+    /// The vertices being dataized now, from the outermost to the
+    /// innermost one.
+    stack: Vec<u32>,
+    /// How many new vertices make the GC run, or zero if never.
+    gc_threshold: usize,
+    /// The size of the graph that makes the GC run next time.
+    gc_next: usize,
+    /// Everybody who watches the dataization.
+    hooks: Vec<Rc<RefCell<dyn Hook>>>,
+    /// The first error that happened inside of `Relay`, which the
+    /// graph can only report as a string.
+    fault: Option<ReoError>,
+    /// The vertices with the data found for the objects, by the vertices
+    /// of the objects, or `None` if memoization is turned off.
+    memo: Option<HashMap<u32, u32>>,
+    /// All vertices that may be seen in `memo` are below this ID.
+    memo_top: u32,
+    /// How many times atoms were called.
+    calls: usize,
+    /// The vertices the data was looked for in, during the last
+    /// dataization, in the order they were met.
+    trail: Vec<u32>,
+    /// Where the snapshots go, if they are taken.
+    snapshots: Option<snapshots::Recorder>,
+    /// Where the atoms print to, like `org.eolang.io.stdout`.
+    stdout: Rc<RefCell<dyn Write>>,
+    /// Where the atoms read from, like `org.eolang.io.stdin`.
+    stdin: Rc<RefCell<dyn BufRead>>,
 }
 
 #[cfg(test)]
@@ -0,0 +1,316 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use anyhow::Result;
+use sodg::Sodg;
+use std::collections::HashSet;
+use std::fmt;
+
+/// How bad a [`Finding`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The graph may be dataized, but most probably it's a mistake.
+    Warning,
+    /// The dataization will fail, if it gets here.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A problem found by [`lint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    /// How bad it is.
+    pub severity: Severity,
+    /// The vertex where it is found.
+    pub v: u32,
+    /// The name of the rule that found it.
+    pub rule: &'static str,
+    /// What exactly is wrong.
+    pub msg: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ν{}: {}: {} [{}]",
+            self.v, self.severity, self.msg, self.rule
+        )
+    }
+}
+
+/// A rule, which finds problems in the graph, returning them
+/// as pairs of a vertex and a message.
+pub type Rule = fn(&mut Sodg) -> Result<Vec<(u32, String)>>;
+
+/// All rules of [`lint`], with their names and severities.
+pub const RULES: [(&str, Severity, Rule); 7] = [
+    ("pi-and-phi", Severity::Error, pi_and_phi),
+    ("beta-kids", Severity::Error, beta_kids),
+    ("lambda-utf8", Severity::Error, lambda_utf8),
+    ("delta-data", Severity::Warning, delta_data),
+    ("alpha-gaps", Severity::Warning, alpha_gaps),
+    ("dangling-rho", Severity::Warning, dangling_rho),
+    ("unreachable", Severity::Warning, unreachable),
+];
+
+/// Check the graph with all [`RULES`] and return what they found,
+/// sorted by vertices:
+///
+/// ```
+/// use reo::{lint, Severity};
+/// use sodg::Sodg;
+/// let mut g = Sodg::empty();
+/// g.add(0)?;
+/// g.add(1)?;
+/// g.bind(0, 1, "Δ")?;
+/// let found = lint(&mut g)?;
+/// assert_eq!(1, found.len());
+/// assert_eq!(Severity::Warning, found[0].severity);
+/// assert_eq!("ν0: warning: Δ points to ν1, which has no data [delta-data]", found[0].to_string());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn lint(g: &mut Sodg) -> Result<Vec<Finding>> {
+    let mut all = vec![];
+    for (rule, severity, check) in RULES {
+        for (v, msg) in check(g)? {
+            all.push(Finding {
+                severity,
+                v,
+                rule,
+                msg,
+            });
+        }
+    }
+    all.sort_by(|a, b| a.v.cmp(&b.v).then(b.severity.cmp(&a.severity)));
+    Ok(all)
+}
+
+/// All vertices of the graph, sorted by their IDs.
+fn sorted(g: &Sodg) -> Vec<u32> {
+    let mut ids = g.ids();
+    ids.sort_unstable();
+    ids
+}
+
+/// A vertex can't have both `π` and `φ`.
+fn pi_and_phi(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        if g.kid(v, "π").is_some() && g.kid(v, "φ").is_some() {
+            found.push((v, "it has both π and φ".to_string()));
+        }
+    }
+    Ok(found)
+}
+
+/// The vertex that `β` points to must have exactly one kid, which
+/// is the attribute to take.
+fn beta_kids(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        if let Some(beta) = g.kid(v, "β") {
+            let total = g.kids(beta)?.len();
+            if total != 1 {
+                found.push((
+                    v,
+                    format!("β points to ν{beta}, which has {total} kids, instead of one"),
+                ));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The vertex that `λ` points to must have the name of the atom,
+/// in UTF-8.
+fn lambda_utf8(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        if let Some(lambda) = g.kid(v, "λ") {
+            let d = g.data(lambda)?;
+            if d.is_empty() {
+                found.push((v, format!("λ points to ν{lambda}, which has no data")));
+            } else if d.to_utf8().is_err() {
+                found.push((
+                    v,
+                    format!("λ points to ν{lambda}, which has no UTF-8 string: {d}"),
+                ));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The vertex that `Δ` points to should have data. It's only a warning,
+/// since an empty string or empty bytes are legal, but a vertex that
+/// was never PUT looks exactly the same.
+fn delta_data(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        if let Some(delta) = g.kid(v, "Δ") {
+            if g.data(delta)?.is_empty() {
+                found.push((v, format!("Δ points to ν{delta}, which has no data")));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The attributes `α0`, `α1`, and so on, must go without gaps.
+fn alpha_gaps(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        let alphas: HashSet<usize> = g
+            .kids(v)?
+            .iter()
+            .filter_map(|(a, _)| a.strip_prefix('α').and_then(|i| i.parse().ok()))
+            .collect();
+        if let Some(max) = alphas.iter().max() {
+            let missed: Vec<String> = (0..*max)
+                .filter(|i| !alphas.contains(i))
+                .map(|i| format!("α{i}"))
+                .collect();
+            if !missed.is_empty() {
+                found.push((
+                    v,
+                    format!("it has α{max}, but doesn't have {}", missed.join(", ")),
+                ));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// The vertex that `ρ` points to must be a parent, which has an
+/// edge to this vertex.
+fn dangling_rho(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut found = vec![];
+    for v in sorted(g) {
+        if let Some(rho) = g.kid(v, "ρ") {
+            if !g.kids(rho)?.iter().any(|(_, k)| *k == v) {
+                found.push((v, format!("ρ points to ν{rho}, which has no edges to ν{v}")));
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// All vertices must be reachable from `ν0`.
+fn unreachable(g: &mut Sodg) -> Result<Vec<(u32, String)>> {
+    let mut seen = HashSet::new();
+    let mut todo = vec![0];
+    while let Some(v) = todo.pop() {
+        if !seen.insert(v) {
+            continue;
+        }
+        if let Ok(kids) = g.kids(v) {
+            todo.extend(kids.into_iter().map(|(_, k)| k));
+        }
+    }
+    Ok(sorted(g)
+        .into_iter()
+        .filter(|v| !seen.contains(v))
+        .map(|v| (v, "it is not reachable from ν0".to_string()))
+        .collect())
+}
+
+#[cfg(test)]
+use sodg::{Hex, Script};
+
+#[cfg(test)]
+fn deployed(script: &str) -> Result<Sodg> {
+    let mut g = Sodg::empty();
+    Script::from_str(script).deploy_to(&mut g)?;
+    Ok(g)
+}
+
+#[test]
+fn finds_nothing_in_clean_graph() -> Result<()> {
+    let mut g = deployed(
+        "
+        ADD(0);
+        ADD(1); BIND(0, 1, foo); BIND(1, 0, ρ);
+        ADD(2); BIND(1, 2, Δ); PUT(2, 00-2A);
+        ADD(3); BIND(1, 3, α0); ADD(4); BIND(1, 4, α1);
+        ",
+    )?;
+    assert!(lint(&mut g)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn finds_all_problems() -> Result<()> {
+    let mut g = deployed(
+        "
+        ADD(0);
+        ADD(1); BIND(0, 1, foo);
+        ADD(2); BIND(1, 2, β); ADD(3); BIND(2, 3, a); BIND(2, 3, b);
+        ADD(4); BIND(1, 4, λ); PUT(4, FF-FE);
+        ADD(5); BIND(1, 5, Δ);
+        BIND(1, 3, α0); BIND(1, 3, α2);
+        ADD(6); BIND(3, 6, ρ);
+        ADD(7); BIND(0, 7, bar); BIND(7, 1, π); BIND(7, 3, φ);
+        ADD(8);
+        ",
+    )?;
+    let found = lint(&mut g)?;
+    let rules: Vec<&str> = found.iter().map(|f| f.rule).collect();
+    assert_eq!(
+        vec![
+            "beta-kids",
+            "lambda-utf8",
+            "delta-data",
+            "alpha-gaps",
+            "dangling-rho",
+            "pi-and-phi",
+            "unreachable"
+        ],
+        rules
+    );
+    assert_eq!(
+        vec![1, 1, 1, 1, 3, 7, 8],
+        found.iter().map(|f| f.v).collect::<Vec<u32>>()
+    );
+    assert!(found[3].msg.contains("doesn't have α1"), "{}", found[3]);
+    Ok(())
+}
+
+#[test]
+fn finds_lambda_without_data() -> Result<()> {
+    let mut g = Sodg::empty();
+    g.add(0)?;
+    g.add(1)?;
+    g.bind(0, 1, "λ")?;
+    g.add(2)?;
+    g.put(2, &Hex::from_str_bytes("org.eolang.io.stdout"))?;
+    g.bind(1, 2, "λ")?;
+    let found = lint(&mut g)?;
+    assert_eq!(1, found.len());
+    assert_eq!(0, found[0].v);
+    Ok(())
+}
+
+#[test]
+fn allows_empty_string() -> Result<()> {
+    let mut g = deployed(
+        "
+        ADD(0);
+        ADD(1); BIND(0, 1, foo); BIND(1, 0, ρ);
+        ADD(2); BIND(1, 2, Δ);
+        ",
+    )?;
+    g.put(2, &Hex::empty())?;
+    let found = lint(&mut g)?;
+    assert_eq!(1, found.len());
+    assert_eq!(Severity::Warning, found[0].severity);
+    Ok(())
+}
@@ -1,6 +1,12 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
 // SPDX-License-Identifier: MIT
 
+//! An EO array is a vertex with its elements attached as ordered
+//! `α0`, `α1`, ..., `αN` kids, without gaps. Such a vertex is usually
+//! made by an application of `org.eolang.array` to the elements,
+//! which are pushed into the copy as varargs.
+
+use crate::scripts::copy_of_int;
 use crate::Universe;
 use anyhow::{anyhow, Result};
 
@@ -11,16 +17,128 @@ pub fn register(uni: &mut Universe) {
 }
 
 /// EO atom `array.length`.
-pub fn array_length(_uni: &mut Universe, _v: u32) -> Result<u32> {
-    Err(anyhow!("Not implemented yet"))
+pub fn array_length(uni: &mut Universe, v: u32) -> Result<u32> {
+    let arr = uni.find(format!("ν{}.ρ", v).as_str())?;
+    let total = elements(uni, arr);
+    copy_of_int(uni, total as i64)
 }
 
 /// EO atom `array.at`.
-pub fn array_at(_uni: &mut Universe, _v: u32) -> Result<u32> {
-    Err(anyhow!("Not implemented yet"))
+pub fn array_at(uni: &mut Universe, v: u32) -> Result<u32> {
+    let arr = uni.find(format!("ν{}.ρ", v).as_str())?;
+    let i = uni.dataize(format!("ν{}.α0", v).as_str())?.to_i64()?;
+    let total = elements(uni, arr);
+    if i < 0 || i as usize >= total {
+        return Err(anyhow!(
+            "Index {i} is out of bounds of ν{arr}, which has {total} elements"
+        ));
+    }
+    uni.kid(arr, format!("α{i}").as_str())
+        .ok_or_else(|| anyhow!("There is no α{i} in ν{arr}"))
+}
+
+/// Count the elements of the array, which are `α0`, `α1`, and so on.
+fn elements(uni: &Universe, arr: u32) -> usize {
+    let mut total = 0;
+    while uni.kid(arr, format!("α{total}").as_str()).is_some() {
+        total += 1;
+    }
+    total
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+/// Build `org.eolang.int` reachable from the root, so that
+/// [`copy_of_int`] can find it.
+#[cfg(test)]
+fn make_int_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    let int = uni.add()?;
+    uni.bind(eolang, int, "int")?;
+    Ok(())
+}
+
+/// Make an array with the given elements and a vertex that emulates
+/// an `array.at`/`array.length`-style call on it, with `ρ` attached
+/// and `α0` (the index) attached, if provided.
+#[cfg(test)]
+fn make_call(uni: &mut Universe, items: &[i64], idx: Option<i64>) -> Result<u32> {
+    let arr = uni.add()?;
+    for (i, item) in items.iter().enumerate() {
+        let e = uni.add()?;
+        uni.bind(arr, e, format!("α{i}").as_str())?;
+        let d = uni.add()?;
+        uni.bind(e, d, "Δ")?;
+        uni.put(d, Hex::from(*item))?;
+    }
+    let v = uni.add()?;
+    uni.bind(v, arr, "ρ")?;
+    if let Some(x) = idx {
+        let x_v = uni.add()?;
+        uni.bind(v, x_v, "α0")?;
+        let x_d = uni.add()?;
+        uni.bind(x_v, x_d, "Δ")?;
+        uni.put(x_d, Hex::from(x))?;
+    }
+    Ok(v)
+}
+
+#[test]
+fn counts_elements() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, &[1, 2, 3], None)?;
+    let result = array_length(&mut uni, v)?;
+    assert_eq!(3, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn counts_empty_array() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, &[], None)?;
+    let result = array_length(&mut uni, v)?;
+    assert_eq!(0, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn takes_element_by_index() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, &[7, 8, 42], Some(2))?;
+    let result = array_at(&mut uni, v)?;
+    assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn fails_when_index_is_too_big() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, &[7, 8], Some(2))?;
+    assert!(
+        array_at(&mut uni, v).is_err(),
+        "Out of bounds index must return an error, not panic"
+    );
+    Ok(())
 }
 
 #[test]
-fn simple() {
-    // assert_eq!(1, total);
+fn fails_when_index_is_negative() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, &[7, 8], Some(-1))?;
+    assert!(
+        array_at(&mut uni, v).is_err(),
+        "Negative index must return an error, not panic"
+    );
+    Ok(())
 }
@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! An EO bool keeps a single byte in `Δ`, which is `01` for `TRUE`
+//! and `00` for `FALSE`.
+
+use crate::scripts::copy_of_bool;
+use crate::Universe;
+use anyhow::{anyhow, Result};
+
+/// Register all known atoms in the Universe.
+pub fn register(uni: &mut Universe) {
+    uni.register("org.eolang.bool$if", bool_if);
+    uni.register("org.eolang.bool$and", bool_and);
+    uni.register("org.eolang.bool$or", bool_or);
+    uni.register("org.eolang.bool$not", bool_not);
+}
+
+/// Dataize the attribute of the vertex and make sure it is a bool.
+fn flag(uni: &mut Universe, v: u32, a: &str) -> Result<bool> {
+    let hex = uni.dataize(format!("ν{v}.{a}").as_str())?;
+    if hex.len() != 1 {
+        return Err(anyhow!(
+            "The ν{v}.{a} is not a bool, it has {} bytes instead of one",
+            hex.len()
+        ));
+    }
+    Ok(hex.to_bool())
+}
+
+/// EO atom `bool.if`.
+///
+/// Only the condition in `ρ` is dataized here. The selected branch,
+/// `α0` or `α1`, is returned as is, while the other one is not
+/// touched at all.
+pub fn bool_if(uni: &mut Universe, v: u32) -> Result<u32> {
+    let branch = if flag(uni, v, "ρ")? { "α0" } else { "α1" };
+    uni.find(format!("ν{v}.{branch}").as_str())
+}
+
+/// EO atom `bool.and`, which stops at the first `FALSE`.
+pub fn bool_and(uni: &mut Universe, v: u32) -> Result<u32> {
+    let mut r = flag(uni, v, "ρ")?;
+    let mut i = 0;
+    while r && uni.kid(v, format!("α{i}").as_str()).is_some() {
+        r = flag(uni, v, format!("α{i}").as_str())?;
+        i += 1;
+    }
+    copy_of_bool(uni, r)
+}
+
+/// EO atom `bool.or`, which stops at the first `TRUE`.
+pub fn bool_or(uni: &mut Universe, v: u32) -> Result<u32> {
+    let mut r = flag(uni, v, "ρ")?;
+    let mut i = 0;
+    while !r && uni.kid(v, format!("α{i}").as_str()).is_some() {
+        r = flag(uni, v, format!("α{i}").as_str())?;
+        i += 1;
+    }
+    copy_of_bool(uni, r)
+}
+
+/// EO atom `bool.not`.
+pub fn bool_not(uni: &mut Universe, v: u32) -> Result<u32> {
+    let r = flag(uni, v, "ρ")?;
+    copy_of_bool(uni, !r)
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+/// Build `org.eolang.bool` reachable from the root, so that
+/// [`copy_of_bool`] can find it.
+#[cfg(test)]
+fn make_bool_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    let bool = uni.add()?;
+    uni.bind(eolang, bool, "bool")?;
+    Ok(())
+}
+
+/// Make a vertex that emulates a `bool.and`-style call,
+/// with `ρ` and all the arguments attached as `α0`, `α1`, and so on.
+/// An argument without data can't be dataized.
+#[cfg(test)]
+fn make_call(uni: &mut Universe, rho: bool, args: Vec<Option<Hex>>) -> Result<u32> {
+    let v = uni.add()?;
+    let rho_v = uni.add()?;
+    uni.bind(v, rho_v, "ρ")?;
+    let rho_d = uni.add()?;
+    uni.bind(rho_v, rho_d, "Δ")?;
+    uni.put(rho_d, Hex::from(rho))?;
+    for (i, arg) in args.into_iter().enumerate() {
+        let x_v = uni.add()?;
+        uni.bind(v, x_v, format!("α{i}").as_str())?;
+        if let Some(d) = arg {
+            let x_d = uni.add()?;
+            uni.bind(x_v, x_d, "Δ")?;
+            uni.put(x_d, d)?;
+        }
+    }
+    Ok(v)
+}
+
+#[test]
+fn selects_first_branch() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bool_object(&mut uni)?;
+    let v = make_call(&mut uni, true, vec![Some(Hex::from(42)), None])?;
+    let result = bool_if(&mut uni, v)?;
+    assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn selects_second_branch() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bool_object(&mut uni)?;
+    let v = make_call(&mut uni, false, vec![None, Some(Hex::from(42))])?;
+    let result = bool_if(&mut uni, v)?;
+    assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn stops_and_at_first_false() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bool_object(&mut uni)?;
+    let v = make_call(&mut uni, true, vec![Some(Hex::from(false)), None])?;
+    let result = bool_and(&mut uni, v)?;
+    assert!(!uni.dataize(format!("ν{result}").as_str())?.to_bool());
+    Ok(())
+}
+
+#[test]
+fn stops_or_at_first_true() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bool_object(&mut uni)?;
+    let v = make_call(&mut uni, false, vec![Some(Hex::from(true)), None])?;
+    let result = bool_or(&mut uni, v)?;
+    assert!(uni.dataize(format!("ν{result}").as_str())?.to_bool());
+    Ok(())
+}
+
+#[test]
+fn fails_on_non_bool_condition() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bool_object(&mut uni)?;
+    let v = make_call(&mut uni, true, vec![])?;
+    let cond = uni.find(format!("ν{v}.ρ.Δ").as_str()).unwrap();
+    uni.put(cond, Hex::from(42))?;
+    assert!(
+        bool_not(&mut uni, v).is_err(),
+        "Eight bytes are not a bool, an error is expected"
+    );
+    Ok(())
+}
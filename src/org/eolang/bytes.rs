@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::scripts::{copy_of_bool, copy_of_bytes, copy_of_int};
+use crate::Universe;
+use anyhow::{anyhow, Context, Result};
+use sodg::Hex;
+
+/// Register all known atoms in the Universe.
+pub fn register(uni: &mut Universe) {
+    uni.register("org.eolang.bytes$concat", bytes_concat);
+    uni.register("org.eolang.bytes$slice", bytes_slice);
+    uni.register("org.eolang.bytes$size", bytes_size);
+    uni.register("org.eolang.bytes$eq", bytes_eq);
+    uni.register("org.eolang.bytes$as-int", bytes_as_int);
+}
+
+/// EO atom `bytes.concat`.
+pub fn bytes_concat(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{v}.ρ").as_str())?;
+    let x = uni.dataize(format!("ν{v}.α0").as_str())?;
+    copy_of_bytes(uni, rho.concat(&x))
+}
+
+/// EO atom `bytes.slice`, where `α0` is the position of the first
+/// byte and `α1` is the number of bytes to take.
+pub fn bytes_slice(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{v}.ρ").as_str())?;
+    let start = uni.dataize(format!("ν{v}.α0").as_str())?.to_i64()?;
+    let len = uni.dataize(format!("ν{v}.α1").as_str())?.to_i64()?;
+    let total = rho.len();
+    if start < 0 || len < 0 || (start as usize).saturating_add(len as usize) > total {
+        return Err(anyhow!(
+            "Can't take {len} bytes from position {start} of {total} bytes"
+        ));
+    }
+    let slice = &rho.bytes()[start as usize..(start + len) as usize];
+    copy_of_bytes(uni, Hex::from_slice(slice))
+}
+
+/// EO atom `bytes.size`.
+pub fn bytes_size(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{v}.ρ").as_str())?;
+    copy_of_int(uni, rho.len() as i64)
+}
+
+/// EO atom `bytes.eq`.
+pub fn bytes_eq(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{v}.ρ").as_str())?;
+    let x = uni.dataize(format!("ν{v}.α0").as_str())?;
+    copy_of_bool(uni, rho == x)
+}
+
+/// EO atom `bytes.as-int`, which expects exactly eight bytes.
+pub fn bytes_as_int(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni
+        .dataize(format!("ν{v}.ρ").as_str())?
+        .to_i64()
+        .context(format!("The ν{v}.ρ can't be an integer"))?;
+    copy_of_int(uni, rho)
+}
+
+/// Build `org.eolang.bytes` and its siblings reachable from the root,
+/// so that [`copy_of_bytes`] and friends can find them.
+#[cfg(test)]
+fn make_bytes_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    for name in ["int", "bool", "bytes"] {
+        let obj = uni.add()?;
+        uni.bind(eolang, obj, name)?;
+    }
+    Ok(())
+}
+
+/// Make a vertex that emulates a `bytes.slice`-style call,
+/// with `ρ` and all the arguments attached as `α0`, `α1`, and so on.
+#[cfg(test)]
+fn make_call(uni: &mut Universe, rho: Hex, args: Vec<Hex>) -> Result<u32> {
+    let v = uni.add()?;
+    let rho_v = uni.add()?;
+    uni.bind(v, rho_v, "ρ")?;
+    let rho_d = uni.add()?;
+    uni.bind(rho_v, rho_d, "Δ")?;
+    uni.put(rho_d, rho)?;
+    for (i, arg) in args.into_iter().enumerate() {
+        let x_v = uni.add()?;
+        uni.bind(v, x_v, format!("α{i}").as_str())?;
+        let x_d = uni.add()?;
+        uni.bind(x_v, x_d, "Δ")?;
+        uni.put(x_d, arg)?;
+    }
+    Ok(v)
+}
+
+#[test]
+fn concatenates_bytes() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bytes_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_vec(vec![0xCA]),
+        vec![Hex::from_vec(vec![0xFE])],
+    )?;
+    let result = bytes_concat(&mut uni, v)?;
+    assert_eq!("CA-FE", uni.dataize(format!("ν{result}").as_str())?.print());
+    Ok(())
+}
+
+#[test]
+fn slices_bytes() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bytes_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_vec(vec![0x01, 0x02, 0x03, 0x04]),
+        vec![Hex::from(1), Hex::from(2)],
+    )?;
+    let result = bytes_slice(&mut uni, v)?;
+    assert_eq!("02-03", uni.dataize(format!("ν{result}").as_str())?.print());
+    Ok(())
+}
+
+#[test]
+fn fails_to_slice_out_of_range() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bytes_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_vec(vec![0x01, 0x02]),
+        vec![Hex::from(1), Hex::from(5)],
+    )?;
+    assert!(
+        bytes_slice(&mut uni, v).is_err(),
+        "Out of range slice must return an error, not panic"
+    );
+    Ok(())
+}
+
+#[test]
+fn compares_bytes() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bytes_object(&mut uni)?;
+    let v = make_call(&mut uni, Hex::from(42), vec![Hex::from(42)])?;
+    let result = bytes_eq(&mut uni, v)?;
+    assert!(uni.dataize(format!("ν{result}").as_str())?.to_bool());
+    Ok(())
+}
+
+#[test]
+fn fails_to_make_int_of_short_bytes() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_bytes_object(&mut uni)?;
+    let v = make_call(&mut uni, Hex::from_vec(vec![0x01, 0x02]), vec![])?;
+    assert!(
+        bytes_as_int(&mut uni, v).is_err(),
+        "Two bytes can't be an integer, an error is expected"
+    );
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! An EO float keeps its value in `Δ` as eight big-endian bytes
+//! of an IEEE-754 double. The arithmetic follows IEEE-754 too, so
+//! division by zero gives an infinity instead of an error.
+
+use crate::scripts::{copy_of_bool, copy_of_float, copy_of_int};
+use crate::Universe;
+use anyhow::{anyhow, Result};
+
+/// Register all known atoms in the Universe.
+pub fn register(uni: &mut Universe) {
+    uni.register("org.eolang.float$plus", float_plus);
+    uni.register("org.eolang.float$minus", float_minus);
+    uni.register("org.eolang.float$times", float_times);
+    uni.register("org.eolang.float$div", float_div);
+    uni.register("org.eolang.float$lt", float_lt);
+    uni.register("org.eolang.float$gt", float_gt);
+    uni.register("org.eolang.float$eq", float_eq);
+    uni.register("org.eolang.float$as-int", float_as_int);
+}
+
+/// Dataize `ρ` (the left operand) and `α0` (the right operand)
+/// of the vertex.
+fn operands(uni: &mut Universe, v: u32) -> Result<(f64, f64)> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_f64()?;
+    let x = uni.dataize(format!("ν{}.α0", v).as_str())?.to_f64()?;
+    Ok((rho, x))
+}
+
+/// EO atom `float.plus`.
+pub fn float_plus(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_float(uni, rho + x)
+}
+
+/// EO atom `float.minus`.
+pub fn float_minus(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_float(uni, rho - x)
+}
+
+/// EO atom `float.times`.
+pub fn float_times(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_float(uni, rho * x)
+}
+
+/// EO atom `float.div`.
+pub fn float_div(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_float(uni, rho / x)
+}
+
+/// EO atom `float.lt`.
+pub fn float_lt(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho < x)
+}
+
+/// EO atom `float.gt`.
+pub fn float_gt(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho > x)
+}
+
+/// EO atom `float.eq`.
+pub fn float_eq(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho == x)
+}
+
+/// EO atom `float.as-int`, which drops the fractional part.
+pub fn float_as_int(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_f64()?;
+    if !rho.is_finite() || rho < i64::MIN as f64 || rho >= i64::MAX as f64 {
+        return Err(anyhow!("Can't turn {rho} into an integer"));
+    }
+    copy_of_int(uni, rho as i64)
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+/// Build `org.eolang.float` and its siblings reachable from the root,
+/// so that [`copy_of_float`] and friends can find them.
+#[cfg(test)]
+fn make_float_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    for name in ["int", "bool", "float"] {
+        let obj = uni.add()?;
+        uni.bind(eolang, obj, name)?;
+    }
+    Ok(())
+}
+
+/// Make a vertex that emulates a `float.plus`-style call,
+/// with `ρ` (the left operand) and `α0` (the right operand) attached.
+#[cfg(test)]
+fn make_call(uni: &mut Universe, rho: f64, x: f64) -> Result<u32> {
+    let v = uni.add()?;
+    let rho_v = uni.add()?;
+    uni.bind(v, rho_v, "ρ")?;
+    let rho_d = uni.add()?;
+    uni.bind(rho_v, rho_d, "Δ")?;
+    uni.put(rho_d, Hex::from(rho))?;
+    let x_v = uni.add()?;
+    uni.bind(v, x_v, "α0")?;
+    let x_d = uni.add()?;
+    uni.bind(x_v, x_d, "Δ")?;
+    uni.put(x_d, Hex::from(x))?;
+    Ok(v)
+}
+
+#[test]
+fn adds_two_floats() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_float_object(&mut uni)?;
+    let v = make_call(&mut uni, 40.5, 1.5)?;
+    let result = float_plus(&mut uni, v)?;
+    assert_eq!(42.0, uni.dataize(format!("ν{result}").as_str())?.to_f64()?);
+    Ok(())
+}
+
+#[test]
+fn divides_by_zero_into_infinity() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_float_object(&mut uni)?;
+    let v = make_call(&mut uni, 1.0, 0.0)?;
+    let result = float_div(&mut uni, v)?;
+    assert_eq!(
+        f64::INFINITY,
+        uni.dataize(format!("ν{result}").as_str())?.to_f64()?
+    );
+    Ok(())
+}
+
+#[test]
+fn compares_two_floats() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_float_object(&mut uni)?;
+    let v = make_call(&mut uni, 0.1, 0.2)?;
+    let lt = float_lt(&mut uni, v)?;
+    assert!(uni.dataize(format!("ν{lt}").as_str())?.to_bool());
+    let eq = float_eq(&mut uni, v)?;
+    assert!(!uni.dataize(format!("ν{eq}").as_str())?.to_bool());
+    Ok(())
+}
+
+#[test]
+fn truncates_float_into_int() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_float_object(&mut uni)?;
+    let v = make_call(&mut uni, -42.9, 0.0)?;
+    let result = float_as_int(&mut uni, v)?;
+    assert_eq!(-42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn fails_to_turn_nan_into_int() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_float_object(&mut uni)?;
+    let v = make_call(&mut uni, f64::NAN, 0.0)?;
+    assert!(
+        float_as_int(&mut uni, v).is_err(),
+        "NaN can't be an integer, an error is expected"
+    );
+    Ok(())
+}
@@ -1,31 +1,62 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
 // SPDX-License-Identifier: MIT
 
-use crate::scripts::copy_of_int;
+use crate::scripts::{copy_of_bool, copy_of_float, copy_of_int, copy_of_string};
 use crate::Universe;
 use anyhow::{anyhow, Result};
 
 /// Register all known atoms in the Universe.
 pub fn register(uni: &mut Universe) {
     uni.register("org.eolang.int$plus", int_plus);
+    uni.register("org.eolang.int$minus", int_minus);
     uni.register("org.eolang.int$times", int_times);
     uni.register("org.eolang.int$div", int_div);
+    uni.register("org.eolang.int$mod", int_mod);
+    uni.register("org.eolang.int$neg", int_neg);
+    uni.register("org.eolang.int$lt", int_lt);
+    uni.register("org.eolang.int$gt", int_gt);
+    uni.register("org.eolang.int$lte", int_lte);
+    uni.register("org.eolang.int$gte", int_gte);
+    uni.register("org.eolang.int$eq", int_eq);
+    uni.register("org.eolang.int$and", int_and);
+    uni.register("org.eolang.int$or", int_or);
+    uni.register("org.eolang.int$xor", int_xor);
+    uni.register("org.eolang.int$not", int_not);
+    uni.register("org.eolang.int$left", int_left);
+    uni.register("org.eolang.int$right", int_right);
+    uni.register("org.eolang.int$as-float", int_as_float);
+    uni.register("org.eolang.int$as-string", int_as_string);
 }
 
-/// EO atom `int.plus`.
-pub fn int_plus(uni: &mut Universe, v: u32) -> Result<u32> {
+/// Dataize `ρ` (the left operand) and `α0` (the right operand)
+/// of the vertex.
+fn operands(uni: &mut Universe, v: u32) -> Result<(i64, i64)> {
     let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
     let x = uni.dataize(format!("ν{}.α0", v).as_str())?.to_i64()?;
+    Ok((rho, x))
+}
+
+/// EO atom `int.plus`.
+pub fn int_plus(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
     let sum = rho
         .checked_add(x)
         .ok_or_else(|| anyhow!("Integer overflow in {rho} + {x}"))?;
     copy_of_int(uni, sum)
 }
 
+/// EO atom `int.minus`.
+pub fn int_minus(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    let diff = rho
+        .checked_sub(x)
+        .ok_or_else(|| anyhow!("Integer overflow in {rho} - {x}"))?;
+    copy_of_int(uni, diff)
+}
+
 /// EO atom `int.times`.
 pub fn int_times(uni: &mut Universe, v: u32) -> Result<u32> {
-    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
-    let x = uni.dataize(format!("ν{}.α0", v).as_str())?.to_i64()?;
+    let (rho, x) = operands(uni, v)?;
     let product = rho
         .checked_mul(x)
         .ok_or_else(|| anyhow!("Integer overflow in {rho} * {x}"))?;
@@ -34,8 +65,7 @@ pub fn int_times(uni: &mut Universe, v: u32) -> Result<u32> {
 
 /// EO atom `int.div`.
 pub fn int_div(uni: &mut Universe, v: u32) -> Result<u32> {
-    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
-    let x = uni.dataize(format!("ν{}.α0", v).as_str())?.to_i64()?;
+    let (rho, x) = operands(uni, v)?;
     if x == 0 {
         return Err(anyhow!("Can't divide {rho} by zero"));
     }
@@ -45,46 +75,169 @@ pub fn int_div(uni: &mut Universe, v: u32) -> Result<u32> {
     copy_of_int(uni, quotient)
 }
 
+/// EO atom `int.mod`.
+///
+/// The sign of the result is the same as the sign of `ρ`.
+pub fn int_mod(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    if x == 0 {
+        return Err(anyhow!("Can't take {rho} modulo zero"));
+    }
+    let rem = rho
+        .checked_rem(x)
+        .ok_or_else(|| anyhow!("Integer overflow in {rho} % {x}"))?;
+    copy_of_int(uni, rem)
+}
+
+/// EO atom `int.neg`.
+pub fn int_neg(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
+    let neg = rho
+        .checked_neg()
+        .ok_or_else(|| anyhow!("Integer overflow in -{rho}"))?;
+    copy_of_int(uni, neg)
+}
+
+/// EO atom `int.lt`.
+pub fn int_lt(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho < x)
+}
+
+/// EO atom `int.gt`.
+pub fn int_gt(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho > x)
+}
+
+/// EO atom `int.lte`.
+pub fn int_lte(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho <= x)
+}
+
+/// EO atom `int.gte`.
+pub fn int_gte(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho >= x)
+}
+
+/// EO atom `int.eq`.
+pub fn int_eq(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_bool(uni, rho == x)
+}
+
+/// EO atom `int.and`, which is a bitwise AND.
+pub fn int_and(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_int(uni, rho & x)
+}
+
+/// EO atom `int.or`, which is a bitwise OR.
+pub fn int_or(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_int(uni, rho | x)
+}
+
+/// EO atom `int.xor`, which is a bitwise XOR.
+pub fn int_xor(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    copy_of_int(uni, rho ^ x)
+}
+
+/// EO atom `int.not`, which is a bitwise NOT.
+pub fn int_not(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
+    copy_of_int(uni, !rho)
+}
+
+/// EO atom `int.left`, which shifts the bits of `ρ` to the left.
+pub fn int_left(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    let shifted = u32::try_from(x)
+        .ok()
+        .and_then(|bits| rho.checked_shl(bits))
+        .ok_or_else(|| anyhow!("Can't shift {rho} left by {x} bits"))?;
+    copy_of_int(uni, shifted)
+}
+
+/// EO atom `int.right`, which shifts the bits of `ρ` to the right,
+/// keeping the sign.
+pub fn int_right(uni: &mut Universe, v: u32) -> Result<u32> {
+    let (rho, x) = operands(uni, v)?;
+    let shifted = u32::try_from(x)
+        .ok()
+        .and_then(|bits| rho.checked_shr(bits))
+        .ok_or_else(|| anyhow!("Can't shift {rho} right by {x} bits"))?;
+    copy_of_int(uni, shifted)
+}
+
+/// EO atom `int.as-float`.
+pub fn int_as_float(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
+    copy_of_float(uni, rho as f64)
+}
+
+/// EO atom `int.as-string`.
+pub fn int_as_string(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = uni.dataize(format!("ν{}.ρ", v).as_str())?.to_i64()?;
+    copy_of_string(uni, rho.to_string().as_str())
+}
+
 #[cfg(test)]
 use sodg::Hex;
 
 /// Build `org.eolang.int` reachable from the root, so that
 /// [`copy_of_int`] can find it.
 #[cfg(test)]
-fn make_int_object(uni: &mut Universe) {
-    let root = uni.add();
+fn make_int_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
     assert_eq!(0, root);
-    let org = uni.add();
-    uni.bind(root, org, "org");
-    let eolang = uni.add();
-    uni.bind(org, eolang, "eolang");
-    let int = uni.add();
-    uni.bind(eolang, int, "int");
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    let int = uni.add()?;
+    uni.bind(eolang, int, "int")?;
+    Ok(())
+}
+
+/// Build `org.eolang.bool`, `org.eolang.float`, and `org.eolang.string`
+/// next to `org.eolang.int`, for the atoms that return them.
+#[cfg(test)]
+fn make_other_objects(uni: &mut Universe) -> Result<()> {
+    let eolang = uni.find("Φ.org.eolang")?;
+    for name in ["bool", "float", "string"] {
+        let obj = uni.add()?;
+        uni.bind(eolang, obj, name)?;
+    }
+    Ok(())
 }
 
 /// Make a vertex that emulates an `int.div`/`int.plus`-style call,
 /// with `ρ` (the left operand) and `α0` (the right operand) attached.
 #[cfg(test)]
-fn make_call(uni: &mut Universe, rho: i64, x: i64) -> u32 {
-    let v = uni.add();
-    let rho_v = uni.add();
-    uni.bind(v, rho_v, "ρ");
-    let rho_d = uni.add();
-    uni.bind(rho_v, rho_d, "Δ");
-    uni.put(rho_d, Hex::from(rho));
-    let x_v = uni.add();
-    uni.bind(v, x_v, "α0");
-    let x_d = uni.add();
-    uni.bind(x_v, x_d, "Δ");
-    uni.put(x_d, Hex::from(x));
-    v
+fn make_call(uni: &mut Universe, rho: i64, x: i64) -> Result<u32> {
+    let v = uni.add()?;
+    let rho_v = uni.add()?;
+    uni.bind(v, rho_v, "ρ")?;
+    let rho_d = uni.add()?;
+    uni.bind(rho_v, rho_d, "Δ")?;
+    uni.put(rho_d, Hex::from(rho))?;
+    let x_v = uni.add()?;
+    uni.bind(v, x_v, "α0")?;
+    let x_d = uni.add()?;
+    uni.bind(x_v, x_d, "Δ")?;
+    uni.put(x_d, Hex::from(x))?;
+    Ok(v)
 }
 
 #[test]
 fn adds_two_integers() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, 40, 2);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 40, 2)?;
     let result = int_plus(&mut uni, v)?;
     assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
     Ok(())
@@ -93,63 +246,175 @@ fn adds_two_integers() -> Result<()> {
 #[test]
 fn multiplies_two_integers() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, 6, 7);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 6, 7)?;
     let result = int_times(&mut uni, v)?;
     assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
     Ok(())
 }
 
 #[test]
-fn fails_when_plus_overflows() {
+fn fails_when_plus_overflows() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, i64::MAX, 1);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, i64::MAX, 1)?;
     assert!(
         int_plus(&mut uni, v).is_err(),
         "Overflowing addition must return an error, not panic or wrap around"
     );
+    Ok(())
 }
 
 #[test]
-fn fails_when_times_overflows() {
+fn fails_when_times_overflows() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, i64::MAX, 2);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, i64::MAX, 2)?;
     assert!(
         int_times(&mut uni, v).is_err(),
         "Overflowing multiplication must return an error, not panic or wrap around"
     );
+    Ok(())
 }
 
 #[test]
 fn divides_two_integers() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, 12, 4);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 12, 4)?;
     let result = int_div(&mut uni, v)?;
     assert_eq!(3, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
     Ok(())
 }
 
 #[test]
-fn fails_to_divide_by_zero() {
+fn fails_to_divide_by_zero() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, 42, 0);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 42, 0)?;
     assert!(
         int_div(&mut uni, v).is_err(),
         "Division by zero must return an error, not panic"
     );
+    Ok(())
 }
 
 #[test]
-fn fails_when_div_overflows() {
+fn fails_when_div_overflows() -> Result<()> {
     let mut uni = Universe::empty();
-    make_int_object(&mut uni);
-    let v = make_call(&mut uni, i64::MIN, -1);
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, i64::MIN, -1)?;
     assert!(
         int_div(&mut uni, v).is_err(),
         "Overflowing division must return an error, not panic"
     );
+    Ok(())
+}
+
+#[test]
+fn subtracts_two_integers() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 50, 8)?;
+    let result = int_minus(&mut uni, v)?;
+    assert_eq!(42, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn fails_when_minus_overflows() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, i64::MIN, 1)?;
+    assert!(
+        int_minus(&mut uni, v).is_err(),
+        "Overflowing subtraction must return an error, not panic or wrap around"
+    );
+    Ok(())
+}
+
+#[test]
+fn takes_remainder() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, -7, 3)?;
+    let result = int_mod(&mut uni, v)?;
+    assert_eq!(-1, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn fails_to_take_modulo_zero() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 42, 0)?;
+    assert!(
+        int_mod(&mut uni, v).is_err(),
+        "Modulo zero must return an error, not panic"
+    );
+    Ok(())
+}
+
+#[test]
+fn fails_when_neg_overflows() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, i64::MIN, 0)?;
+    assert!(
+        int_neg(&mut uni, v).is_err(),
+        "Overflowing negation must return an error, not panic or wrap around"
+    );
+    Ok(())
+}
+
+#[test]
+fn compares_two_integers() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    make_other_objects(&mut uni)?;
+    let v = make_call(&mut uni, 1, 2)?;
+    let lt = int_lt(&mut uni, v)?;
+    assert!(uni.dataize(format!("ν{lt}").as_str())?.to_bool());
+    let gte = int_gte(&mut uni, v)?;
+    assert!(!uni.dataize(format!("ν{gte}").as_str())?.to_bool());
+    Ok(())
+}
+
+#[test]
+fn xors_two_integers() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 0b1100, 0b1010)?;
+    let result = int_xor(&mut uni, v)?;
+    assert_eq!(
+        0b0110,
+        uni.dataize(format!("ν{result}").as_str())?.to_i64()?
+    );
+    Ok(())
+}
+
+#[test]
+fn fails_to_shift_too_far() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    let v = make_call(&mut uni, 1, 64)?;
+    assert!(
+        int_left(&mut uni, v).is_err(),
+        "Shifting by 64 bits must return an error, not panic"
+    );
+    Ok(())
+}
+
+#[test]
+fn prints_integer_as_string() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_int_object(&mut uni)?;
+    make_other_objects(&mut uni)?;
+    let v = make_call(&mut uni, -42, 0)?;
+    let result = int_as_string(&mut uni, v)?;
+    assert_eq!(
+        "-42",
+        uni.dataize(format!("ν{result}").as_str())?.to_utf8()?
+    );
+    Ok(())
 }
@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! The atoms here don't touch the console directly, they go through
+//! [`Universe::print`] and [`Universe::read_line`], so the output and
+//! the input can be redirected with [`Universe::with_stdout`] and
+//! [`Universe::with_stdin`].
+
+use crate::scripts::{copy_of_bool, copy_of_string};
+use crate::Universe;
+use anyhow::{Context, Result};
+
+/// Register all known atoms in the Universe.
+pub fn register(uni: &mut Universe) {
+    uni.register("org.eolang.io.stdout", io_stdout);
+    uni.register("org.eolang.io.stdin", io_stdin);
+    uni.register("org.eolang.io.stdin$next-line", io_stdin_next_line);
+}
+
+/// EO atom `io.stdout`, which prints `α0` and returns `TRUE`.
+pub fn io_stdout(uni: &mut Universe, v: u32) -> Result<u32> {
+    let text = uni
+        .dataize(format!("ν{v}.α0").as_str())?
+        .to_utf8()
+        .context(format!("The ν{v}.α0 is not a valid UTF-8 string"))?;
+    uni.print(text.as_str())?;
+    copy_of_bool(uni, true)
+}
+
+/// EO atom `io.stdin`, which reads everything left in the input.
+pub fn io_stdin(uni: &mut Universe, _v: u32) -> Result<u32> {
+    let all = uni.read_all()?;
+    copy_of_string(uni, all.as_str())
+}
+
+/// EO atom `io.stdin.next-line`, which reads the next line of the
+/// input, or returns an empty string at the end of it.
+pub fn io_stdin_next_line(uni: &mut Universe, _v: u32) -> Result<u32> {
+    let line = uni.read_line()?.unwrap_or_default();
+    copy_of_string(uni, line.as_str())
+}
+
+#[cfg(test)]
+use sodg::{Hex, Script, Sodg};
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+use std::io::Cursor;
+
+#[cfg(test)]
+use std::rc::Rc;
+
+#[test]
+fn prints_hello_world() -> Result<()> {
+    let mut s = Script::from_str(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, bool);
+        ADD($ν4);
+        BIND($ν2, $ν4, io);
+        ADD($ν5);
+        BIND($ν4, $ν5, stdout);
+        ADD($ν6);
+        BIND($ν5, $ν6, λ);
+        PUT($ν6, 6F-72-67-2E-65-6F-6C-61-6E-67-2E-69-6F-2E-73-74-64-6F-75-74);
+        ADD($ν7);
+        BIND($ν7, $ν5, π);
+        ADD($ν8);
+        BIND($ν7, $ν8, α0);
+        ADD($ν9);
+        BIND($ν8, $ν9, Δ);
+        PUT($ν9, 48-65-6C-6C-6F-2C-20-77-6F-72-6C-64-21-0A);
+        BIND(ν0, $ν7, app);
+        ",
+    );
+    let mut g = Sodg::empty();
+    s.deploy_to(&mut g)?;
+    let out = Rc::new(RefCell::new(Vec::new()));
+    let mut uni = Universe::from_graph(g).with_stdout(out.clone());
+    register(&mut uni);
+    assert!(uni.dataize("Φ.app")?.to_bool());
+    assert_eq!("Hello, world!\n", String::from_utf8(out.borrow().clone())?);
+    Ok(())
+}
+
+/// Build `org.eolang.string` reachable from the root, so that
+/// [`copy_of_string`] can find it.
+#[cfg(test)]
+fn make_string_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    let string = uni.add()?;
+    uni.bind(eolang, string, "string")?;
+    Ok(())
+}
+
+#[test]
+fn reads_lines_one_by_one() -> Result<()> {
+    let input = Rc::new(RefCell::new(Cursor::new("first\r\nsecond\n")));
+    let mut uni = Universe::empty().with_stdin(input);
+    make_string_object(&mut uni)?;
+    let v = uni.add()?;
+    for expected in ["first", "second", ""] {
+        let line = io_stdin_next_line(&mut uni, v)?;
+        assert_eq!(
+            expected,
+            uni.dataize(format!("ν{line}").as_str())?.to_utf8()?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn reads_entire_input() -> Result<()> {
+    let input = Rc::new(RefCell::new(Cursor::new("one\ntwo\n")));
+    let mut uni = Universe::empty().with_stdin(input);
+    make_string_object(&mut uni)?;
+    let v = uni.add()?;
+    let all = io_stdin(&mut uni, v)?;
+    assert_eq!(
+        Hex::from_str_bytes("one\ntwo\n"),
+        uni.dataize(format!("ν{all}").as_str())?
+    );
+    Ok(())
+}
@@ -2,12 +2,22 @@
 // SPDX-License-Identifier: MIT
 
 mod array;
+mod bool;
+mod bytes;
+mod float;
 mod int;
+mod io;
+mod string;
 
 use crate::Universe;
 
 /// Register all known atoms in the Universe.
 pub fn register(uni: &mut Universe) {
     int::register(uni);
+    bool::register(uni);
+    float::register(uni);
+    string::register(uni);
+    bytes::register(uni);
+    io::register(uni);
     array::register(uni);
 }
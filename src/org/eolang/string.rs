@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! An EO string keeps its text in `Δ` as UTF-8 bytes. Its length
+//! and positions are counted in characters, not in bytes.
+
+use crate::scripts::{copy_of_int, copy_of_string};
+use crate::Universe;
+use anyhow::{anyhow, Context, Result};
+
+/// Register all known atoms in the Universe.
+pub fn register(uni: &mut Universe) {
+    uni.register("org.eolang.string$length", string_length);
+    uni.register("org.eolang.string$slice", string_slice);
+    uni.register("org.eolang.string$concat", string_concat);
+}
+
+/// Dataize the attribute of the vertex and decode it as UTF-8 text.
+fn text(uni: &mut Universe, v: u32, a: &str) -> Result<String> {
+    uni.dataize(format!("ν{v}.{a}").as_str())?
+        .to_utf8()
+        .context(format!("The ν{v}.{a} is not a valid UTF-8 string"))
+}
+
+/// EO atom `string.length`.
+pub fn string_length(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = text(uni, v, "ρ")?;
+    copy_of_int(uni, rho.chars().count() as i64)
+}
+
+/// EO atom `string.slice`, where `α0` is the position of the first
+/// character and `α1` is the number of characters to take.
+pub fn string_slice(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = text(uni, v, "ρ")?;
+    let start = uni.dataize(format!("ν{v}.α0").as_str())?.to_i64()?;
+    let len = uni.dataize(format!("ν{v}.α1").as_str())?.to_i64()?;
+    let total = rho.chars().count();
+    if start < 0 || len < 0 || (start as usize).saturating_add(len as usize) > total {
+        return Err(anyhow!(
+            "Can't take {len} characters from position {start} of a string of {total} characters"
+        ));
+    }
+    let slice: String = rho
+        .chars()
+        .skip(start as usize)
+        .take(len as usize)
+        .collect();
+    copy_of_string(uni, slice.as_str())
+}
+
+/// EO atom `string.concat`.
+pub fn string_concat(uni: &mut Universe, v: u32) -> Result<u32> {
+    let rho = text(uni, v, "ρ")?;
+    let x = text(uni, v, "α0")?;
+    copy_of_string(uni, format!("{rho}{x}").as_str())
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+/// Build `org.eolang.string` and its siblings reachable from the root,
+/// so that [`copy_of_string`] and friends can find them.
+#[cfg(test)]
+fn make_string_object(uni: &mut Universe) -> Result<()> {
+    let root = uni.add()?;
+    assert_eq!(0, root);
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    for name in ["int", "string"] {
+        let obj = uni.add()?;
+        uni.bind(eolang, obj, name)?;
+    }
+    Ok(())
+}
+
+/// Make a vertex that emulates a `string.slice`-style call,
+/// with `ρ` and all the arguments attached as `α0`, `α1`, and so on.
+#[cfg(test)]
+fn make_call(uni: &mut Universe, rho: Hex, args: Vec<Hex>) -> Result<u32> {
+    let v = uni.add()?;
+    let rho_v = uni.add()?;
+    uni.bind(v, rho_v, "ρ")?;
+    let rho_d = uni.add()?;
+    uni.bind(rho_v, rho_d, "Δ")?;
+    uni.put(rho_d, rho)?;
+    for (i, arg) in args.into_iter().enumerate() {
+        let x_v = uni.add()?;
+        uni.bind(v, x_v, format!("α{i}").as_str())?;
+        let x_d = uni.add()?;
+        uni.bind(x_v, x_d, "Δ")?;
+        uni.put(x_d, arg)?;
+    }
+    Ok(v)
+}
+
+#[test]
+fn counts_characters() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_string_object(&mut uni)?;
+    let v = make_call(&mut uni, Hex::from_str_bytes("привет"), vec![])?;
+    let result = string_length(&mut uni, v)?;
+    assert_eq!(6, uni.dataize(format!("ν{result}").as_str())?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn slices_string() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_string_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_str_bytes("привет"),
+        vec![Hex::from(1), Hex::from(3)],
+    )?;
+    let result = string_slice(&mut uni, v)?;
+    assert_eq!(
+        "рив",
+        uni.dataize(format!("ν{result}").as_str())?.to_utf8()?
+    );
+    Ok(())
+}
+
+#[test]
+fn fails_to_slice_out_of_range() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_string_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_str_bytes("abc"),
+        vec![Hex::from(2), Hex::from(2)],
+    )?;
+    assert!(
+        string_slice(&mut uni, v).is_err(),
+        "Out of range slice must return an error, not panic"
+    );
+    Ok(())
+}
+
+#[test]
+fn concatenates_strings() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_string_object(&mut uni)?;
+    let v = make_call(
+        &mut uni,
+        Hex::from_str_bytes("Hello, "),
+        vec![Hex::from_str_bytes("world!")],
+    )?;
+    let result = string_concat(&mut uni, v)?;
+    assert_eq!(
+        "Hello, world!",
+        uni.dataize(format!("ν{result}").as_str())?.to_utf8()?
+    );
+    Ok(())
+}
+
+#[test]
+fn fails_on_broken_utf8() -> Result<()> {
+    let mut uni = Universe::empty();
+    make_string_object(&mut uni)?;
+    let v = make_call(&mut uni, Hex::from_vec(vec![0xC3, 0x28]), vec![])?;
+    assert!(
+        string_length(&mut uni, v).is_err(),
+        "Invalid UTF-8 must return an error, not panic"
+    );
+    Ok(())
+}
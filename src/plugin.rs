@@ -0,0 +1,281 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! Atoms that live in shared libraries, which are loaded into
+//! a [`Universe`] at startup by [`Universe::load_plugin`].
+//!
+//! Such a library talks to `reo` only through the C ABI, so it may be
+//! written in any language and compiled by any compiler. It must
+//! export this function:
+//!
+//! ```c
+//! int reo_plugin_init(const ReoHost *host);
+//! ```
+//!
+//! It is called once, right after the library is loaded. The plugin
+//! checks that `host->abi` equals [`REO_ABI_VERSION`], registers its
+//! atoms through `host->register`, and returns zero. Later, each atom
+//! is called with a pointer to [`ReoApi`], which it uses in order to
+//! read and modify the graph. All functions return zero on success.
+
+use crate::Universe;
+use anyhow::{anyhow, Context, Result};
+use libloading::{Library, Symbol};
+use log::debug;
+use sodg::Hex;
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::rc::Rc;
+use std::slice;
+
+/// The version of the ABI, which is incremented every time
+/// [`ReoHost`] or [`ReoApi`] change.
+pub const REO_ABI_VERSION: u32 = 1;
+
+/// The name of the function the plugin must export.
+const INIT: &[u8] = b"reo_plugin_init\0";
+
+/// A call of an atom, opaque for the plugin.
+#[repr(C)]
+pub struct ReoCall {
+    _private: [u8; 0],
+}
+
+/// An atom implemented by a plugin: it gets the vertex `v` and
+/// puts the ID of the vertex with the result into `out`.
+pub type ReoAtom =
+    unsafe extern "C" fn(api: *const ReoApi, call: *mut ReoCall, v: u32, out: *mut u32) -> c_int;
+
+/// What a plugin gets in `reo_plugin_init`.
+#[repr(C)]
+pub struct ReoHost {
+    /// The version of the ABI of the host, see [`REO_ABI_VERSION`].
+    pub abi: u32,
+    /// What must be passed to `register`.
+    pub registrar: *mut c_void,
+    /// Register an atom under the given name, like `org.example.foo`.
+    pub register:
+        unsafe extern "C" fn(registrar: *mut c_void, name: *const c_char, atom: ReoAtom) -> c_int,
+}
+
+/// The functions an atom may use, while being called.
+#[repr(C)]
+pub struct ReoApi {
+    /// Add a new vertex and put its ID into `out`.
+    pub add: unsafe extern "C" fn(call: *mut ReoCall, out: *mut u32) -> c_int,
+    /// Make an edge from `v1` to `v2` labeled as `a`.
+    pub bind: unsafe extern "C" fn(call: *mut ReoCall, v1: u32, v2: u32, a: *const c_char) -> c_int,
+    /// Put `len` bytes of data into the vertex.
+    pub put: unsafe extern "C" fn(call: *mut ReoCall, v: u32, data: *const u8, len: usize) -> c_int,
+    /// Find the vertex by the locator and put its ID into `out`.
+    pub find: unsafe extern "C" fn(call: *mut ReoCall, loc: *const c_char, out: *mut u32) -> c_int,
+    /// Dataize the object by the locator, copying no more than `cap`
+    /// bytes of the data into `buf`, while the full size of the data
+    /// goes into `len`.
+    pub dataize: unsafe extern "C" fn(
+        call: *mut ReoCall,
+        loc: *const c_char,
+        buf: *mut u8,
+        cap: usize,
+        len: *mut usize,
+    ) -> c_int,
+    /// Explain why the atom is going to return non-zero.
+    pub fail: unsafe extern "C" fn(call: *mut ReoCall, msg: *const c_char),
+}
+
+/// What is behind the [`ReoCall`] pointer.
+struct Call<'a> {
+    uni: &'a mut Universe,
+    error: Option<anyhow::Error>,
+}
+
+static API: ReoApi = ReoApi {
+    add: api_add,
+    bind: api_bind,
+    put: api_put,
+    find: api_find,
+    dataize: api_dataize,
+    fail: api_fail,
+};
+
+impl Universe {
+    /// Load a shared library with atoms and register all of them.
+    /// Returns the number of atoms registered.
+    pub fn load_plugin(&mut self, path: &Path) -> Result<usize> {
+        let lib = Rc::new(
+            unsafe { Library::new(path) }
+                .context(anyhow!("Can't load plugin '{}'", path.display()))?,
+        );
+        let mut atoms: Vec<(String, ReoAtom)> = vec![];
+        let host = ReoHost {
+            abi: REO_ABI_VERSION,
+            registrar: &mut atoms as *mut Vec<(String, ReoAtom)> as *mut c_void,
+            register: host_register,
+        };
+        let code = unsafe {
+            let init: Symbol<unsafe extern "C" fn(*const ReoHost) -> c_int> =
+                lib.get(INIT).context(anyhow!(
+                    "There is no reo_plugin_init() in '{}'",
+                    path.display()
+                ))?;
+            init(&host)
+        };
+        if code != 0 {
+            return Err(anyhow!(
+                "The plugin '{}' failed to initialize, reo_plugin_init() returned {code}",
+                path.display()
+            ));
+        }
+        let total = atoms.len();
+        for (name, atom) in atoms {
+            debug!("Atom {name} registered by '{}'", path.display());
+            let lib = lib.clone();
+            self.register(name.as_str(), move |uni: &mut Universe, v: u32| {
+                let _ = &lib;
+                call(uni, atom, v)
+            });
+        }
+        Ok(total)
+    }
+}
+
+/// Call the atom from the plugin.
+fn call(uni: &mut Universe, atom: ReoAtom, v: u32) -> Result<u32> {
+    let mut c = Call { uni, error: None };
+    let mut out = 0;
+    let code = unsafe { atom(&API, &mut c as *mut Call as *mut ReoCall, v, &mut out) };
+    if code == 0 {
+        Ok(out)
+    } else {
+        Err(c
+            .error
+            .take()
+            .unwrap_or_else(|| anyhow!("The atom returned {code}")))
+    }
+}
+
+/// Run the function over the Universe of the call, remembering
+/// the error, if it happens. A panic is caught and turned into
+/// an error too, since it must not unwind into the plugin.
+unsafe fn attempt(call: *mut ReoCall, f: impl FnOnce(&mut Universe) -> Result<()>) -> c_int {
+    let c = &mut *(call as *mut Call);
+    let r = catch_unwind(AssertUnwindSafe(|| f(c.uni)))
+        .unwrap_or_else(|p| Err(anyhow!("Panic in reo: {}", panic_message(p.as_ref()))));
+    match r {
+        Ok(()) => 0,
+        Err(e) => {
+            c.error = Some(e);
+            -1
+        }
+    }
+}
+
+/// The message of a caught panic.
+fn panic_message(p: &(dyn std::any::Any + Send)) -> String {
+    p.downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| p.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Turn a C string into a Rust one.
+unsafe fn text<'a>(s: *const c_char) -> Result<&'a str> {
+    if s.is_null() {
+        return Err(anyhow!("A NULL string given by the plugin"));
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .context("A string given by the plugin is not UTF-8")
+}
+
+unsafe extern "C" fn host_register(
+    registrar: *mut c_void,
+    name: *const c_char,
+    atom: ReoAtom,
+) -> c_int {
+    let atoms = &mut *(registrar as *mut Vec<(String, ReoAtom)>);
+    catch_unwind(AssertUnwindSafe(|| match text(name) {
+        Ok(n) => {
+            atoms.push((n.to_string(), atom));
+            0
+        }
+        Err(_) => -1,
+    }))
+    .unwrap_or(-1)
+}
+
+unsafe extern "C" fn api_add(call: *mut ReoCall, out: *mut u32) -> c_int {
+    attempt(call, |uni| {
+        *out = uni.add()?;
+        Ok(())
+    })
+}
+
+unsafe extern "C" fn api_bind(call: *mut ReoCall, v1: u32, v2: u32, a: *const c_char) -> c_int {
+    attempt(call, |uni| uni.bind(v1, v2, text(a)?))
+}
+
+unsafe extern "C" fn api_put(call: *mut ReoCall, v: u32, data: *const u8, len: usize) -> c_int {
+    attempt(call, |uni| {
+        let bytes = if len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(data, len)
+        };
+        uni.put(v, Hex::from_slice(bytes))
+    })
+}
+
+unsafe extern "C" fn api_find(call: *mut ReoCall, loc: *const c_char, out: *mut u32) -> c_int {
+    attempt(call, |uni| {
+        *out = uni.find(text(loc)?)?;
+        Ok(())
+    })
+}
+
+unsafe extern "C" fn api_dataize(
+    call: *mut ReoCall,
+    loc: *const c_char,
+    buf: *mut u8,
+    cap: usize,
+    len: *mut usize,
+) -> c_int {
+    attempt(call, |uni| {
+        let data = uni.dataize(text(loc)?)?;
+        let bytes = data.bytes();
+        *len = bytes.len();
+        let n = cap.min(bytes.len());
+        if n > 0 {
+            buf.copy_from(bytes.as_ptr(), n);
+        }
+        Ok(())
+    })
+}
+
+unsafe extern "C" fn api_fail(call: *mut ReoCall, msg: *const c_char) {
+    let c = &mut *(call as *mut Call);
+    let e = catch_unwind(AssertUnwindSafe(|| match text(msg) {
+        Ok(m) => anyhow!("{m}"),
+        Err(e) => e,
+    }))
+    .unwrap_or_else(|p| anyhow!("Panic in reo: {}", panic_message(p.as_ref())));
+    c.error = Some(e);
+}
+
+#[test]
+fn catches_panic_in_api() -> Result<()> {
+    let mut uni = Universe::empty();
+    let mut c = Call {
+        uni: &mut uni,
+        error: None,
+    };
+    let code = unsafe {
+        attempt(&mut c as *mut Call as *mut ReoCall, |_| {
+            panic!("intentional")
+        })
+    };
+    assert_eq!(-1, code);
+    assert!(c.error.unwrap().to_string().contains("intentional"));
+    Ok(())
+}
@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::Universe;
+use anyhow::{anyhow, Result};
+use glob::Pattern;
+use std::time::{Duration, Instant};
+
+/// The result of one test, made by [`Universe::test`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    /// The name of the object, like `org.example.app`.
+    pub name: String,
+    /// Why it failed, or `None` if it passed.
+    pub failure: Option<String>,
+    /// How long it took.
+    pub time: Duration,
+}
+
+impl Outcome {
+    /// Did it pass?
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl Universe {
+    /// Find all objects that have the `expected` attribute, and
+    /// whose names match the glob pattern, like `org.example.*`.
+    /// The names are sorted and don't have the leading `Φ.`.
+    pub fn tests(&self, filter: &str) -> Result<Vec<String>> {
+        let pattern = Pattern::new(filter).map_err(|e| anyhow!("Wrong filter '{filter}': {e}"))?;
+        let mut names: Vec<String> = self
+            .paths()
+            .into_iter()
+            .filter(|(v, _)| self.g.kid(*v, "expected").is_some())
+            .filter_map(|(_, path)| path.strip_prefix("Φ.").map(|p| p.to_string()))
+            .filter(|name| pattern.matches(name))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    /// Dataize the object and its `expected` attribute and compare
+    /// the results:
+    ///
+    /// ```
+    /// use reo::Universe;
+    /// use sodg::Hex;
+    /// let mut uni = Universe::empty();
+    /// let root = uni.add()?;
+    /// let v1 = uni.add()?;
+    /// uni.bind(root, v1, "foo")?;
+    /// let v2 = uni.add()?;
+    /// uni.bind(v1, v2, "Δ")?;
+    /// uni.put(v2, Hex::from(42))?;
+    /// let v3 = uni.add()?;
+    /// uni.bind(v1, v3, "expected")?;
+    /// uni.bind(v3, v2, "Δ")?;
+    /// assert_eq!(vec!["foo".to_string()], uni.tests("*")?);
+    /// assert!(uni.test("foo").passed());
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn test(&mut self, name: &str) -> Outcome {
+        let start = Instant::now();
+        let failure = match self.dataize(format!("Φ.{name}.expected").as_str()) {
+            Err(e) => Some(format!("Can't dataize the expected: {e:#}")),
+            Ok(expected) => match self.dataize(format!("Φ.{name}").as_str()) {
+                Err(e) => Some(format!("{e:#}")),
+                Ok(actual) if actual != expected => {
+                    Some(format!("Expected {expected}, but got {actual}"))
+                }
+                Ok(_) => None,
+            },
+        };
+        Outcome {
+            name: name.to_string(),
+            failure,
+            time: start.elapsed(),
+        }
+    }
+}
+
+/// Make a JUnit XML report of the outcomes, which CI servers understand.
+pub fn junit(suite: &str, outcomes: &[Outcome]) -> String {
+    let mut lines = vec![
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+        format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+            escape(suite),
+            outcomes.len(),
+            outcomes.iter().filter(|o| !o.passed()).count(),
+            outcomes
+                .iter()
+                .map(|o| o.time)
+                .sum::<Duration>()
+                .as_secs_f64()
+        ),
+    ];
+    for o in outcomes {
+        let head = format!(
+            "  <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+            escape(o.name.as_str()),
+            escape(suite),
+            o.time.as_secs_f64()
+        );
+        match &o.failure {
+            None => lines.push(format!("{head}/>")),
+            Some(f) => {
+                lines.push(format!("{head}>"));
+                lines.push(format!("    <failure message=\"{}\"/>", escape(f.as_str())));
+                lines.push("  </testcase>".to_string());
+            }
+        }
+    }
+    lines.push("</testsuite>".to_string());
+    lines.join("\n") + "\n"
+}
+
+/// Escape the text for an XML attribute.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+/// Make a Universe with `good`, which is `42` as expected,
+/// and `bad`, which is `7`, while `42` is expected.
+#[cfg(test)]
+fn two_tests() -> Result<Universe> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let pkg = uni.add()?;
+    uni.bind(root, pkg, "pkg")?;
+    let fortytwo = uni.add()?;
+    uni.bind(root, fortytwo, "fortytwo")?;
+    let d = uni.add()?;
+    uni.put(d, Hex::from(42))?;
+    uni.bind(fortytwo, d, "Δ")?;
+    for (name, data) in [("good", 42), ("bad", 7)] {
+        let v = uni.add()?;
+        uni.bind(pkg, v, name)?;
+        uni.bind(v, pkg, "ρ")?;
+        let d = uni.add()?;
+        uni.put(d, Hex::from(data))?;
+        uni.bind(v, d, "Δ")?;
+        uni.bind(v, fortytwo, "expected")?;
+    }
+    Ok(uni)
+}
+
+#[test]
+fn finds_tests_by_filter() -> Result<()> {
+    let uni = two_tests()?;
+    assert_eq!(vec!["pkg.bad", "pkg.good"], uni.tests("*")?);
+    assert_eq!(vec!["pkg.good"], uni.tests("pkg.g*")?);
+    assert!(uni.tests("other.*")?.is_empty());
+    assert!(uni.tests("[").is_err());
+    Ok(())
+}
+
+#[test]
+fn runs_tests() -> Result<()> {
+    let mut uni = two_tests()?;
+    assert!(uni.test("pkg.good").passed());
+    let bad = uni.test("pkg.bad");
+    assert_eq!(
+        Some("Expected 00-00-00-00-00-00-00-2A, but got 00-00-00-00-00-00-00-07"),
+        bad.failure.as_deref()
+    );
+    assert!(uni.test("pkg.absent").failure.is_some());
+    Ok(())
+}
+
+#[test]
+fn makes_junit_report() -> Result<()> {
+    let mut uni = two_tests()?;
+    let outcomes = vec![uni.test("pkg.good"), uni.test("pkg.bad")];
+    let xml = junit("app.reo", &outcomes);
+    assert!(xml.contains("<testsuite name=\"app.reo\" tests=\"2\" failures=\"1\""));
+    assert!(xml.contains("<testcase name=\"pkg.good\" classname=\"app.reo\""));
+    assert!(xml.contains("<failure message=\"Expected 00-00-00-00-00-00-00-2A, but got"));
+    assert!(xml.trim_end().ends_with("</testsuite>"));
+    Ok(())
+}
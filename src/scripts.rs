@@ -8,11 +8,55 @@ use sodg::Hex;
 /// Makes a copy of `org.eolang.int` in the Universe. It is assumed
 /// that it already exists there.
 pub fn copy_of_int(uni: &mut Universe, data: i64) -> Result<u32> {
-    let v = uni.add();
-    let int = uni.find("org.eolang.int")?;
-    uni.bind(v, int, "π");
-    let d = uni.add();
-    uni.put(d, Hex::from(data));
-    uni.bind(v, d, "Δ");
+    copy_of(uni, "org.eolang.int", Hex::from(data))
+}
+
+/// Makes a copy of `org.eolang.bool` in the Universe. It is assumed
+/// that it already exists there.
+pub fn copy_of_bool(uni: &mut Universe, data: bool) -> Result<u32> {
+    copy_of(uni, "org.eolang.bool", Hex::from(data))
+}
+
+/// Makes a copy of `org.eolang.float` in the Universe, with the
+/// number stored as eight big-endian bytes of IEEE-754 double. It is
+/// assumed that it already exists there.
+pub fn copy_of_float(uni: &mut Universe, data: f64) -> Result<u32> {
+    copy_of(uni, "org.eolang.float", Hex::from(data))
+}
+
+/// Makes a copy of `org.eolang.string` in the Universe, with the
+/// text stored in UTF-8. It is assumed that it already exists there.
+pub fn copy_of_string(uni: &mut Universe, data: &str) -> Result<u32> {
+    copy_of(uni, "org.eolang.string", Hex::from_str_bytes(data))
+}
+
+/// Makes a copy of `org.eolang.bytes` in the Universe. It is assumed
+/// that it already exists there.
+pub fn copy_of_bytes(uni: &mut Universe, data: Hex) -> Result<u32> {
+    copy_of(uni, "org.eolang.bytes", data)
+}
+
+/// Makes a copy of `org.eolang.array` in the Universe, with the
+/// items attached as `α0`, `α1`, and so on. It is assumed that
+/// it already exists there.
+pub fn copy_of_array(uni: &mut Universe, items: &[u32]) -> Result<u32> {
+    let v = uni.add()?;
+    let obj = uni.find("org.eolang.array")?;
+    uni.bind(v, obj, "π")?;
+    for (i, item) in items.iter().enumerate() {
+        uni.bind(v, *item, format!("α{i}").as_str())?;
+    }
+    Ok(v)
+}
+
+/// Makes a copy of the object found by the locator and attaches
+/// the data to it.
+fn copy_of(uni: &mut Universe, loc: &str, data: Hex) -> Result<u32> {
+    let v = uni.add()?;
+    let obj = uni.find(loc)?;
+    uni.bind(v, obj, "π")?;
+    let d = uni.add()?;
+    uni.put(d, data)?;
+    uni.bind(v, d, "Δ")?;
     Ok(v)
 }
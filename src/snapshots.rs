@@ -0,0 +1,787 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+//! Snapshots of the graph, taken at every step of dataization and
+//! given to a [`SnapshotSink`], see [`Universe::with_snapshot_sink`].
+//!
+//! A snapshot doesn't have the entire graph, but only the changes
+//! made to it since the previous step. The state of the graph at any
+//! step may be rebuilt from them with [`Graph::replay`].
+//!
+//! [`Universe::with_snapshot_sink`]: crate::Universe::with_snapshot_sink
+
+use crate::tracer::escape;
+use anyhow::{anyhow, Context, Result};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use sodg::{Hex, Sodg};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// The attributes of the vertices in DOT, which were added
+/// since the previous state of the graph.
+const COLORS: &str = "fillcolor=aquamarine3,style=filled,";
+
+/// How many last lines of the log go together with a graph.
+const LOG_TAIL: usize = 32;
+
+const MAKEFILE: &str = include_str!("../surge-make/Makefile");
+const DOC_TEX: &str = include_str!("../surge-make/doc.tex");
+const INDEX_HTML: &str = include_str!("snapshots/index.html");
+
+/// A step of dataization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The number of the step, starting from one.
+    pub step: usize,
+    /// The number of the state of the graph, starting from one, which
+    /// is incremented only when the graph changes.
+    pub pos: usize,
+    /// The depth of recursion.
+    pub depth: usize,
+    /// What is happening at this step.
+    pub msg: String,
+    /// The changes made to the graph since the previous step. At the
+    /// first step, they build the graph from scratch.
+    pub changes: Vec<Change>,
+}
+
+impl Snapshot {
+    /// The vertices added at this step.
+    pub fn fresh(&self) -> Vec<u32> {
+        self.changes
+            .iter()
+            .filter_map(|c| match c {
+                Change::Add(v) => Some(*v),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// A change of the graph.
+///
+/// It is printed the way [`sodg::Script`] expects it, except `DROP`,
+/// which is not there:
+///
+/// ```
+/// use reo::Change;
+/// let c: Change = "BIND(ν0, ν1, foo);".parse()?;
+/// assert_eq!(Change::Bind(0, 1, "foo".to_string()), c);
+/// assert_eq!("BIND(ν0, ν1, foo);", c.to_string());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// A vertex is added.
+    Add(u32),
+    /// An edge is added from the first vertex to the second one,
+    /// replacing the edge with the same attribute, if it was there.
+    Bind(u32, u32, String),
+    /// The data is put into the vertex.
+    Put(u32, Hex),
+    /// The vertex is deleted by the GC, together with the edges
+    /// departing from it. No edges arrive to it from the vertices that
+    /// stay in the graph.
+    Drop(u32),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Add(v) => write!(f, "ADD(ν{v});"),
+            Change::Bind(v1, v2, a) => write!(f, "BIND(ν{v1}, ν{v2}, {a});"),
+            Change::Put(v, d) => write!(f, "PUT(ν{v}, {});", d.print()),
+            Change::Drop(v) => write!(f, "DROP(ν{v});"),
+        }
+    }
+}
+
+impl FromStr for Change {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        lazy_static! {
+            static ref LINE: Regex = Regex::new("^([A-Z]+) *\\(([^)]*)\\) *;?$").unwrap();
+        }
+        let cap = LINE
+            .captures(s.trim())
+            .ok_or(anyhow!("Can't parse the change '{s}'"))?;
+        let args: Vec<&str> = cap[2].split(',').map(|t| t.trim()).collect();
+        let vertex = |i: usize| -> Result<u32> {
+            let t = args
+                .get(i)
+                .ok_or(anyhow!("Argument no.{} is missing in '{s}'", i + 1))?;
+            t.trim_start_matches('ν')
+                .parse()
+                .context(anyhow!("Can't parse vertex '{t}' in '{s}'"))
+        };
+        let change = match &cap[1] {
+            "ADD" => Change::Add(vertex(0)?),
+            "BIND" => Change::Bind(
+                vertex(0)?,
+                vertex(1)?,
+                args.get(2)
+                    .ok_or(anyhow!("Attribute is missing in '{s}'"))?
+                    .to_string(),
+            ),
+            "PUT" => Change::Put(
+                vertex(0)?,
+                Hex::from_str(args.get(1).ok_or(anyhow!("Data is missing in '{s}'"))?)?,
+            ),
+            "DROP" => Change::Drop(vertex(0)?),
+            cmd => return Err(anyhow!("Unknown command '{cmd}' in '{s}'")),
+        };
+        Ok(change)
+    }
+}
+
+/// A vertex of the graph, as it is seen in a [`Graph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vertex {
+    /// The ID of it.
+    pub id: u32,
+    /// The data, which may be empty.
+    pub data: Hex,
+    /// The edges departing from it, sorted by their attributes.
+    pub edges: Vec<(String, u32)>,
+}
+
+/// The state of the graph, rebuilt from the [`Change`]s made to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Graph {
+    /// All vertices, by their IDs.
+    vertices: BTreeMap<u32, Vertex>,
+}
+
+impl Graph {
+    /// Make an empty one.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Rebuild the graph as it was right after the given step,
+    /// from all the snapshots taken before it.
+    pub fn replay(snapshots: &[Snapshot], step: usize) -> Result<Self> {
+        let mut g = Self::empty();
+        for s in snapshots.iter().take_while(|s| s.step <= step) {
+            for c in &s.changes {
+                g.apply(c)
+                    .context(anyhow!("Failed to replay step no.{}", s.step))?;
+            }
+        }
+        Ok(g)
+    }
+
+    /// Make one more change.
+    pub fn apply(&mut self, change: &Change) -> Result<()> {
+        match change {
+            Change::Add(v) => {
+                self.vertices.entry(*v).or_insert(Vertex {
+                    id: *v,
+                    data: Hex::empty(),
+                    edges: vec![],
+                });
+            }
+            Change::Bind(v1, v2, a) => {
+                if !self.vertices.contains_key(v2) {
+                    return Err(anyhow!("Can't arrive at ν{v2}, it's absent"));
+                }
+                let vtx = self
+                    .vertices
+                    .get_mut(v1)
+                    .ok_or(anyhow!("Can't depart from ν{v1}, it's absent"))?;
+                vtx.edges.retain(|(e, _)| e != a);
+                vtx.edges.push((a.clone(), *v2));
+                vtx.edges.sort();
+            }
+            Change::Put(v, d) => {
+                self.vertices
+                    .get_mut(v)
+                    .ok_or(anyhow!("Can't put data into ν{v}, it's absent"))?
+                    .data = d.clone();
+            }
+            Change::Drop(v) => {
+                self.vertices
+                    .remove(v)
+                    .ok_or(anyhow!("Can't drop ν{v}, it's absent"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// All vertices, sorted by their IDs.
+    pub fn vertices(&self) -> impl Iterator<Item = &Vertex> {
+        self.vertices.values()
+    }
+
+    /// Make a [`Sodg`] of it.
+    pub fn to_sodg(&self) -> Result<Sodg> {
+        let mut g = Sodg::empty();
+        for v in self.vertices.keys() {
+            g.add(*v)?;
+        }
+        for vtx in self.vertices.values() {
+            if !vtx.data.is_empty() {
+                g.put(vtx.id, &vtx.data)?;
+            }
+            for (a, to) in &vtx.edges {
+                g.bind(vtx.id, *to, a.as_str())?;
+            }
+        }
+        Ok(g)
+    }
+
+    /// Print it as a DOT graph, with these vertices highlighted.
+    pub fn to_dot(&self, fresh: &[u32]) -> Result<String> {
+        lazy_static! {
+            static ref DOT_LINE: Regex = Regex::new("^ +v([0-9]+)\\[.*$").unwrap();
+        }
+        Ok(self
+            .to_sodg()?
+            .to_dot()
+            .split('\n')
+            .map(|t| match DOT_LINE.captures(t) {
+                Some(m) if fresh.contains(&m[1].parse::<u32>().unwrap_or_default()) => {
+                    t.replacen('[', format!("[{COLORS}").as_str(), 1)
+                }
+                _ => t.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+}
+
+/// Where the snapshots go.
+pub trait SnapshotSink {
+    /// Take the next snapshot.
+    fn save(&mut self, snapshot: &Snapshot) -> Result<()>;
+
+    /// The dataization is over, for now. It is called at the end of
+    /// each call to [`Universe::find`](crate::Universe::find), which
+    /// is not made by an atom.
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Collects the changes of the graph and gives them to the sink,
+/// one snapshot per step.
+#[derive(Clone)]
+pub(crate) struct Recorder {
+    /// Where the snapshots go.
+    sink: Rc<RefCell<dyn SnapshotSink>>,
+    /// The number of the last step.
+    step: usize,
+    /// The number of the last state of the graph.
+    pos: usize,
+    /// The changes made since the last step.
+    changes: Vec<Change>,
+}
+
+impl Recorder {
+    /// Make it, starting from the graph as it is now.
+    pub(crate) fn new(sink: Rc<RefCell<dyn SnapshotSink>>, g: &mut Sodg) -> Self {
+        let mut ids = g.ids();
+        ids.sort_unstable();
+        let mut changes: Vec<Change> = ids.iter().map(|v| Change::Add(*v)).collect();
+        for v in &ids {
+            if let Ok(d) = g.data(*v) {
+                if !d.is_empty() {
+                    changes.push(Change::Put(*v, d));
+                }
+            }
+            let mut kids = g.kids(*v).unwrap_or_default();
+            kids.sort();
+            for (a, to) in kids {
+                changes.push(Change::Bind(*v, to, a));
+            }
+        }
+        Recorder {
+            sink,
+            step: 0,
+            pos: 0,
+            changes,
+        }
+    }
+
+    /// Remember the change, until the next step.
+    pub(crate) fn note(&mut self, change: Change) {
+        self.changes.push(change);
+    }
+
+    /// Give a snapshot of the step to the sink.
+    pub(crate) fn record(&mut self, depth: usize, msg: &str) -> Result<()> {
+        self.step += 1;
+        if !self.changes.is_empty() {
+            self.pos += 1;
+        }
+        self.sink.borrow_mut().save(&Snapshot {
+            step: self.step,
+            pos: self.pos,
+            depth,
+            msg: msg.to_string(),
+            changes: std::mem::take(&mut self.changes),
+        })
+    }
+
+    pub(crate) fn flush(&mut self) -> Result<()> {
+        self.sink.borrow_mut().flush()
+    }
+}
+
+/// A sink that keeps all snapshots in memory.
+#[derive(Default)]
+pub struct MemorySink {
+    snapshots: Vec<Snapshot>,
+}
+
+impl MemorySink {
+    /// Make an empty one.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All snapshots taken so far.
+    pub fn snapshots(&self) -> &[Snapshot] {
+        &self.snapshots
+    }
+}
+
+impl SnapshotSink for MemorySink {
+    fn save(&mut self, snapshot: &Snapshot) -> Result<()> {
+        self.snapshots.push(snapshot.clone());
+        Ok(())
+    }
+}
+
+/// A sink that saves each state of the graph as `N.dot` file,
+/// together with `log-N.txt` and a `Makefile`, which renders
+/// all of them into one PDF, with the help of `dot` and `pdflatex`.
+///
+/// It rebuilds the entire graph on every change, so it is slow
+/// for big graphs.
+pub struct DotSink {
+    /// The directory.
+    home: PathBuf,
+    /// The graph, as it is now.
+    graph: Graph,
+    /// The last lines of the log.
+    tail: VecDeque<String>,
+    /// Is the directory ready?
+    ready: bool,
+}
+
+impl DotSink {
+    /// Make a sink that saves files into the directory.
+    pub fn new(home: &Path) -> Self {
+        DotSink {
+            home: home.to_path_buf(),
+            graph: Graph::empty(),
+            tail: VecDeque::new(),
+            ready: false,
+        }
+    }
+
+    /// Create the directory and the files that are always there.
+    fn prepare(&mut self) -> Result<()> {
+        let home = self.home.as_path();
+        fs::create_dir_all(home).context(anyhow!("Can't create directory {}", home.display()))?;
+        fs::write(home.join("Makefile"), MAKEFILE)
+            .context(anyhow!("Can't save Makefile to '{}'", home.display()))?;
+        fs::write(home.join("doc.tex"), DOC_TEX)
+            .context(anyhow!("Can't save doc.tex to '{}'", home.display()))?;
+        fs::write(home.join("list.tex"), b"").context(anyhow!("Can't write empty list.tex"))?;
+        fs::write(home.join("log.txt"), b"").context(anyhow!("Can't write empty log.txt"))?;
+        debug!("Snapshot dir created: {}", home.display());
+        self.ready = true;
+        Ok(())
+    }
+
+    fn append(&self, file: &str, line: &str) -> Result<()> {
+        let mut f = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(self.home.join(file))
+            .context(anyhow!(
+                "Can't open {}/{file} for appending",
+                self.home.display()
+            ))?;
+        writeln!(f, "{line}")?;
+        Ok(())
+    }
+}
+
+impl SnapshotSink for DotSink {
+    fn save(&mut self, snapshot: &Snapshot) -> Result<()> {
+        if !self.ready {
+            self.prepare()?;
+        }
+        let pos = snapshot.pos;
+        if !snapshot.changes.is_empty() {
+            for c in &snapshot.changes {
+                self.graph.apply(c)?;
+            }
+            let dot = self.graph.to_dot(&snapshot.fresh())?;
+            let file = self.home.join(format!("{pos}.dot"));
+            fs::write(&file, &dot).context(anyhow!("Can't save {}", file.display()))?;
+            self.append("list.tex", format!("\\graph{{{pos}}}").as_str())?;
+            debug!("Dot file saved: {} ({} bytes)", file.display(), dot.len());
+        }
+        let line = format!(
+            "{}{}",
+            "  ".repeat(snapshot.depth),
+            snapshot.msg.replace('ν', "v").replace('Δ', "D")
+        );
+        self.append("log.txt", line.as_str())?;
+        self.tail.push_back(line);
+        if self.tail.len() > LOG_TAIL {
+            self.tail.pop_front();
+        }
+        fs::write(
+            self.home.join(format!("log-{pos}.txt")),
+            self.tail
+                .iter()
+                .cloned()
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )?;
+        Ok(())
+    }
+}
+
+/// A sink that saves all snapshots into one HTML file, which
+/// can be opened in a browser without anything else, even offline.
+/// It draws the graph as SVG, with a slider over the steps, and
+/// highlights the vertices added at each step, together with the
+/// line of the log that goes with it.
+pub struct HtmlSink {
+    /// The file.
+    path: PathBuf,
+    /// All snapshots taken so far, in JSON.
+    items: Vec<String>,
+}
+
+impl HtmlSink {
+    /// Make a sink that saves snapshots into the HTML file.
+    pub fn new(path: &Path) -> Self {
+        HtmlSink {
+            path: path.to_path_buf(),
+            items: vec![],
+        }
+    }
+}
+
+impl SnapshotSink for HtmlSink {
+    fn save(&mut self, s: &Snapshot) -> Result<()> {
+        self.items.push(format!(
+            "{{\"step\":{},\"pos\":{},\"depth\":{},\"msg\":\"{}\",\"changes\":[{}]}}",
+            s.step,
+            s.pos,
+            s.depth,
+            escape(s.msg.as_str()),
+            s.changes
+                .iter()
+                .map(|c| match c {
+                    Change::Add(v) => format!("[\"add\",{v}]"),
+                    Change::Bind(v1, v2, a) => format!("[\"bind\",{v1},{v2},\"{}\"]", escape(a)),
+                    Change::Put(v, d) => format!("[\"put\",{v},\"{}\"]", d.print()),
+                    Change::Drop(v) => format!("[\"drop\",{v}]"),
+                })
+                .collect::<Vec<String>>()
+                .join(",")
+        ));
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).context(anyhow!("Can't create directory {}", dir.display()))?;
+        }
+        let html = INDEX_HTML.replace(
+            "/* SNAPSHOTS */",
+            format!("[\n{}\n]", self.items.join(",\n")).as_str(),
+        );
+        fs::write(&self.path, html).context(anyhow!("Can't save {}", self.path.display()))?;
+        debug!(
+            "{} snapshots saved to {}",
+            self.items.len(),
+            self.path.display()
+        );
+        Ok(())
+    }
+}
+
+/// A sink that writes all snapshots into a text file, as they come.
+/// Each step starts with a comment line, which is followed by the
+/// changes, one per line:
+///
+/// ```text
+/// # step 1 at depth 0: #fnd(ν3, Δ, 0): entered...
+/// ADD(ν0);
+/// BIND(ν0, ν1, foo);
+/// # step 2 at depth 1: #dd(ν3): entered...
+/// ```
+///
+/// The file may be read back by [`JournalSink::load`].
+pub struct JournalSink {
+    /// The file.
+    path: PathBuf,
+    /// Where to write, when the file is open.
+    out: Option<BufWriter<fs::File>>,
+}
+
+impl JournalSink {
+    /// Make a sink that writes to the file.
+    pub fn new(path: &Path) -> Self {
+        JournalSink {
+            path: path.to_path_buf(),
+            out: None,
+        }
+    }
+
+    /// Read all snapshots from the file.
+    pub fn load(path: &Path) -> Result<Vec<Snapshot>> {
+        lazy_static! {
+            static ref HEAD: Regex =
+                Regex::new("^# step ([0-9]+) at depth ([0-9]+): (.*)$").unwrap();
+        }
+        let text = fs::read_to_string(path).context(anyhow!("Can't read {}", path.display()))?;
+        let mut all: Vec<Snapshot> = vec![];
+        let mut pos = 0;
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(cap) = HEAD.captures(line) {
+                all.push(Snapshot {
+                    step: cap[1].parse()?,
+                    pos,
+                    depth: cap[2].parse()?,
+                    msg: cap[3].to_string(),
+                    changes: vec![],
+                });
+                continue;
+            }
+            let s = all.last_mut().ok_or(anyhow!(
+                "The line no.{} of {} goes before the first step",
+                i + 1,
+                path.display()
+            ))?;
+            if s.changes.is_empty() {
+                pos += 1;
+                s.pos = pos;
+            }
+            s.changes.push(line.parse().context(anyhow!(
+                "Wrong line no.{} of {}",
+                i + 1,
+                path.display()
+            ))?);
+        }
+        Ok(all)
+    }
+}
+
+impl SnapshotSink for JournalSink {
+    fn save(&mut self, s: &Snapshot) -> Result<()> {
+        if self.out.is_none() {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)
+                    .context(anyhow!("Can't create directory {}", dir.display()))?;
+            }
+            let f = fs::File::create(&self.path)
+                .context(anyhow!("Can't create {}", self.path.display()))?;
+            self.out = Some(BufWriter::new(f));
+        }
+        if let Some(out) = self.out.as_mut() {
+            writeln!(
+                out,
+                "# step {} at depth {}: {}",
+                s.step,
+                s.depth,
+                s.msg.replace('\n', " ")
+            )?;
+            for c in &s.changes {
+                writeln!(out, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if let Some(out) = self.out.as_mut() {
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::Universe;
+
+#[cfg(test)]
+use tempfile::TempDir;
+
+/// Make a Universe where `foo` is a copy of `bar`, which is `42`,
+/// with the given sink.
+#[cfg(test)]
+fn copy_of_bar(sink: Rc<RefCell<dyn SnapshotSink>>) -> Result<Universe> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let bar = uni.add()?;
+    uni.bind(root, bar, "bar")?;
+    let d = uni.add()?;
+    uni.bind(bar, d, "Δ")?;
+    uni.put(d, Hex::from(42))?;
+    let foo = uni.add()?;
+    uni.bind(root, foo, "foo")?;
+    uni.bind(foo, bar, "π")?;
+    Ok(uni.with_snapshot_sink(sink))
+}
+
+#[test]
+fn keeps_snapshots_in_memory() -> Result<()> {
+    let sink = Rc::new(RefCell::new(MemorySink::new()));
+    let mut uni = copy_of_bar(sink.clone())?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let all = sink.borrow().snapshots().to_vec();
+    assert!(all.len() > 2);
+    assert_eq!(Some(4), all.first().map(|s| s.fresh().len()));
+    let changes: Vec<&Snapshot> = all.iter().filter(|s| !s.changes.is_empty()).collect();
+    assert_eq!(changes.len(), all.last().map(|s| s.pos).unwrap_or_default());
+    assert!(changes.iter().skip(1).all(|s| s.changes.len() < 10));
+    Ok(())
+}
+
+#[test]
+fn replays_graph() -> Result<()> {
+    let sink = Rc::new(RefCell::new(MemorySink::new()));
+    let mut uni = copy_of_bar(sink.clone())?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let all = sink.borrow().snapshots().to_vec();
+    let first = Graph::replay(&all, 1)?;
+    assert_eq!(4, first.vertices().count());
+    assert_eq!(
+        Some(&Vertex {
+            id: 3,
+            data: Hex::empty(),
+            edges: vec![("π".to_string(), 1)],
+        }),
+        first.vertices().find(|v| v.id == 3)
+    );
+    let last = Graph::replay(&all, usize::MAX)?;
+    assert_eq!(uni.g.len(), last.vertices().count());
+    for vtx in last.vertices() {
+        let mut kids = uni.kids(vtx.id)?;
+        kids.sort();
+        assert_eq!(kids, vtx.edges);
+        assert_eq!(uni.data(vtx.id)?, vtx.data);
+    }
+    Ok(())
+}
+
+#[test]
+fn replays_gc() -> Result<()> {
+    let mut g = Graph::empty();
+    for c in ["ADD(ν0);", "ADD(ν1);", "BIND(ν0, ν1, x);", "PUT(ν1, 2A);"] {
+        g.apply(&c.parse()?)?;
+    }
+    assert!(g.to_dot(&[1])?.contains(COLORS));
+    g.apply(&"ADD(ν2);".parse()?)?;
+    g.apply(&"BIND(ν0, ν2, x);".parse()?)?;
+    g.apply(&Change::Drop(1))?;
+    assert_eq!(2, g.vertices().count());
+    assert!(g.apply(&"BIND(ν0, ν1, y);".parse()?).is_err());
+    assert!(g.apply(&Change::Drop(1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn prints_and_parses_changes() -> Result<()> {
+    for c in [
+        Change::Add(7),
+        Change::Bind(1, 2, "α0".to_string()),
+        Change::Put(3, Hex::from(42)),
+        Change::Put(4, Hex::empty()),
+        Change::Drop(5),
+    ] {
+        assert_eq!(c, c.to_string().parse()?);
+    }
+    assert!("JUMP(ν1);".parse::<Change>().is_err());
+    Ok(())
+}
+
+#[test]
+fn saves_dot_files() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let home = tmp.path().join("surge");
+    let mut uni = copy_of_bar(Rc::new(RefCell::new(DotSink::new(home.as_path()))))?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert!(home.join("Makefile").exists());
+    assert!(home.join("doc.tex").exists());
+    assert!(fs::read_to_string(home.join("1.dot"))?.contains("digraph"));
+    assert!(fs::read_to_string(home.join("2.dot"))?.contains(COLORS));
+    assert!(fs::read_to_string(home.join("list.tex"))?.contains("\\graph{1}"));
+    assert!(fs::read_to_string(home.join("log-1.txt"))?.contains("#fnd(v3, D, 0)"));
+    Ok(())
+}
+
+#[test]
+fn saves_html_file() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let path = tmp.path().join("surge/index.html");
+    let mut uni = copy_of_bar(Rc::new(RefCell::new(HtmlSink::new(path.as_path()))))?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let html = fs::read_to_string(path)?;
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(!html.contains("/* SNAPSHOTS */"));
+    assert!(html.contains("\"msg\":\"#fnd(ν3, Δ, 0): entered...\""));
+    assert!(html.contains("\"changes\":[[\"add\",0],[\"add\",1],"));
+    assert!(html.contains("[\"put\",2,\"00-00-00-00-00-00-00-2A\"]"));
+    assert!(html.contains("id=\"slider\""));
+    Ok(())
+}
+
+#[test]
+fn saves_and_loads_journal() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let path = tmp.path().join("surge/journal.txt");
+    let mut uni = copy_of_bar(Rc::new(RefCell::new(JournalSink::new(path.as_path()))))?;
+    let memory = Rc::new(RefCell::new(MemorySink::new()));
+    let mut twin = copy_of_bar(memory.clone())?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert_eq!(42, twin.dataize("Φ.foo")?.to_i64()?);
+    assert!(fs::read_to_string(&path)?.starts_with("# step 1 at depth 1: #fnd(ν3, Δ, 0)"));
+    assert_eq!(
+        memory.borrow().snapshots(),
+        JournalSink::load(path.as_path())?
+    );
+    Ok(())
+}
+
+#[test]
+fn replays_graph_after_gc() -> Result<()> {
+    let sink = Rc::new(RefCell::new(MemorySink::new()));
+    let mut uni = copy_of_bar(sink.clone())?.with_memo(false);
+    uni.add()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert!(uni.gc()? > 0);
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let all = sink.borrow().snapshots().to_vec();
+    assert!(all
+        .iter()
+        .any(|s| s.changes.iter().any(|c| matches!(c, Change::Drop(_)))));
+    let last = Graph::replay(&all, usize::MAX)?;
+    let mut ids = uni.g.ids();
+    ids.sort_unstable();
+    assert_eq!(ids, last.vertices().map(|v| v.id).collect::<Vec<u32>>());
+    Ok(())
+}
@@ -0,0 +1,283 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::{Event, Hook, Universe};
+use anyhow::{anyhow, Result};
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Instant;
+
+/// The format of the trace made by [`Tracer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// One JSON object per line, per event, like
+    /// `{"ev":"exit","op":"dd","v":5,"result":7,"depth":3,"ts":120}`.
+    Jsonl,
+    /// The [Trace Event Format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+    /// which Chrome's `about:tracing` and Perfetto can open.
+    Chrome,
+}
+
+impl FromStr for TraceFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "jsonl" => Ok(TraceFormat::Jsonl),
+            "chrome" => Ok(TraceFormat::Chrome),
+            _ => Err(anyhow!(
+                "Unknown trace format '{s}', try 'jsonl' or 'chrome'"
+            )),
+        }
+    }
+}
+
+/// A hook that writes all events of dataization to a writer,
+/// in a machine-readable format. Timestamps are in microseconds
+/// since the tracer was made.
+///
+/// Call [`Tracer::finish`] when the dataization is over, or just drop
+/// the tracer. If the dataization failed, the frames that are still
+/// open are closed, in the Chrome format, to keep the trace balanced.
+pub struct Tracer {
+    /// Where to write.
+    out: Box<dyn Write>,
+    /// The format of the trace.
+    format: TraceFormat,
+    /// When the trace started.
+    start: Instant,
+    /// How many events are written.
+    total: usize,
+    /// The operations entered, but not yet exited.
+    open: Vec<&'static str>,
+    /// Is it finished already?
+    finished: bool,
+}
+
+impl Tracer {
+    /// Make a tracer.
+    pub fn new(out: Box<dyn Write>, format: TraceFormat) -> Self {
+        Tracer {
+            out,
+            format,
+            start: Instant::now(),
+            total: 0,
+            open: vec![],
+            finished: false,
+        }
+    }
+
+    /// Close the trace and flush the writer. Returns the number
+    /// of events written.
+    pub fn finish(&mut self) -> Result<usize> {
+        if self.finished {
+            return Ok(self.total);
+        }
+        self.finished = true;
+        if self.format == TraceFormat::Chrome {
+            while let Some(op) = self.open.pop() {
+                let ts = self.start.elapsed().as_micros();
+                write!(
+                    self.out,
+                    ",\n{{\"name\":\"{op}\",\"ph\":\"E\",\"ts\":{ts},\"pid\":1,\"tid\":1}}"
+                )?;
+                self.total += 1;
+            }
+            if self.total == 0 {
+                write!(self.out, "[")?;
+            }
+            writeln!(self.out, "\n]")?;
+        }
+        self.out.flush()?;
+        Ok(self.total)
+    }
+
+    /// Make a JSON line for the event.
+    fn jsonl(event: &Event, ts: u128) -> String {
+        match event {
+            Event::Enter {
+                op,
+                v,
+                psi,
+                loc,
+                depth,
+                ..
+            } => format!(
+                "{{\"ev\":\"enter\",\"op\":\"{op}\",\"v\":{v},\"psi\":{psi},\"loc\":\"{}\",\"depth\":{depth},\"ts\":{ts}}}",
+                escape(loc)
+            ),
+            Event::Exit {
+                op,
+                v,
+                result,
+                depth,
+                ..
+            } => format!(
+                "{{\"ev\":\"exit\",\"op\":\"{op}\",\"v\":{v},\"result\":{result},\"depth\":{depth},\"ts\":{ts}}}"
+            ),
+        }
+    }
+
+    /// Make a "B" or "E" event of the Trace Event Format.
+    fn chrome(event: &Event, ts: u128) -> String {
+        match event {
+            Event::Enter { op, v, psi, loc, .. } => format!(
+                "{{\"name\":\"{op}\",\"ph\":\"B\",\"ts\":{ts},\"pid\":1,\"tid\":1,\"args\":{{\"v\":{v},\"psi\":{psi},\"loc\":\"{}\"}}}}",
+                escape(loc)
+            ),
+            Event::Exit { op, result, .. } => format!(
+                "{{\"name\":\"{op}\",\"ph\":\"E\",\"ts\":{ts},\"pid\":1,\"tid\":1,\"args\":{{\"result\":{result}}}}}"
+            ),
+        }
+    }
+}
+
+impl Hook for Tracer {
+    fn on(&mut self, _uni: &mut Universe, event: &Event) -> Result<()> {
+        let ts = self.start.elapsed().as_micros();
+        match self.format {
+            TraceFormat::Jsonl => writeln!(self.out, "{}", Self::jsonl(event, ts))?,
+            TraceFormat::Chrome => {
+                let sep = if self.total == 0 { "[" } else { "," };
+                write!(self.out, "{sep}\n{}", Self::chrome(event, ts))?
+            }
+        }
+        match event {
+            Event::Enter { op, .. } => self.open.push(op),
+            Event::Exit { .. } => {
+                self.open.pop();
+            }
+        }
+        self.total += 1;
+        Ok(())
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+/// Escape the text for a JSON string, which may also be
+/// put into an HTML `<script>`.
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '<' => out.push_str("\\u003c"),
+            c if (c as u32) < 0x20 => out.push_str(format!("\\u{:04x}", c as u32).as_str()),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+use sodg::Hex;
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+use std::rc::Rc;
+
+/// A writer that may be read after the [`Tracer`] has it.
+#[cfg(test)]
+#[derive(Clone, Default)]
+struct Buffer(Rc<RefCell<Vec<u8>>>);
+
+#[cfg(test)]
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Dataize `foo`, which is a copy of `bar`, with the tracer attached,
+/// and return the trace.
+#[cfg(test)]
+fn traced(format: TraceFormat) -> Result<String> {
+    let buf = Buffer::default();
+    let tracer = Rc::new(RefCell::new(Tracer::new(Box::new(buf.clone()), format)));
+    let mut uni = Universe::empty().with_hook(tracer.clone());
+    let root = uni.add()?;
+    let bar = uni.add()?;
+    uni.bind(root, bar, "bar")?;
+    let d = uni.add()?;
+    uni.bind(bar, d, "Δ")?;
+    uni.put(d, Hex::from(42))?;
+    let foo = uni.add()?;
+    uni.bind(root, foo, "foo")?;
+    uni.bind(foo, bar, "π")?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert!(tracer.borrow_mut().finish()? > 0);
+    let text = String::from_utf8(buf.0.borrow().clone())?;
+    Ok(text)
+}
+
+#[test]
+fn writes_json_lines() -> Result<()> {
+    let text = traced(TraceFormat::Jsonl)?;
+    assert!(text.lines().all(|l| l.starts_with('{') && l.ends_with('}')));
+    assert!(text
+        .contains("{\"ev\":\"enter\",\"op\":\"dd\",\"v\":3,\"psi\":0,\"loc\":\"ν3\",\"depth\":2,"));
+    assert!(text.contains("\"ev\":\"exit\",\"op\":\"apply\",\"v\":1,\"result\":"));
+    Ok(())
+}
+
+#[test]
+fn writes_chrome_trace() -> Result<()> {
+    let text = traced(TraceFormat::Chrome)?;
+    assert!(
+        text.starts_with("[\n{\"name\":\"fnd\",\"ph\":\"B\""),
+        "{text}"
+    );
+    assert!(text.trim_end().ends_with(']'), "{text}");
+    assert_eq!(
+        text.matches("\"ph\":\"B\"").count(),
+        text.matches("\"ph\":\"E\"").count()
+    );
+    Ok(())
+}
+
+#[test]
+fn closes_frames_of_failed_dataization() -> Result<()> {
+    let buf = Buffer::default();
+    {
+        let tracer = Rc::new(RefCell::new(Tracer::new(
+            Box::new(buf.clone()),
+            TraceFormat::Chrome,
+        )));
+        let mut uni = Universe::empty()
+            .with_max_depth(8)
+            .with_hook(tracer.clone());
+        let root = uni.add()?;
+        let foo = uni.add()?;
+        uni.bind(root, foo, "foo")?;
+        let bar = uni.add()?;
+        uni.bind(foo, bar, "φ")?;
+        uni.bind(bar, foo, "φ")?;
+        assert!(uni.dataize("Φ.foo").is_err());
+    }
+    let text = String::from_utf8(buf.0.borrow().clone())?;
+    assert!(text.trim_end().ends_with(']'), "{text}");
+    assert!(text.matches("\"ph\":\"B\"").count() > 0, "{text}");
+    assert_eq!(
+        text.matches("\"ph\":\"B\"").count(),
+        text.matches("\"ph\":\"E\"").count(),
+        "{text}"
+    );
+    Ok(())
+}
+
+#[test]
+fn escapes_json() {
+    assert_eq!("a\\\"b\\\\c\\u000a", escape("a\"b\\c\n"));
+}
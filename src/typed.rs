@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+use crate::tracer::escape;
+use crate::Universe;
+use anyhow::{anyhow, Result};
+use sodg::Hex;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+/// The type of the data, which tells how to decode it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataType {
+    /// Eight bytes of a signed big-endian integer, like `org.eolang.int`.
+    Int,
+    /// Eight bytes of IEEE-754 double, like `org.eolang.float`.
+    Float,
+    /// UTF-8 text, like `org.eolang.string`.
+    String,
+    /// One byte, where `01` is `TRUE`, like `org.eolang.bool`.
+    Bool,
+    /// Just bytes, like `org.eolang.bytes`.
+    Hex,
+}
+
+/// The EO objects, which the data types are inferred from.
+const OBJECTS: [(&str, DataType); 5] = [
+    ("Φ.org.eolang.int", DataType::Int),
+    ("Φ.org.eolang.float", DataType::Float),
+    ("Φ.org.eolang.string", DataType::String),
+    ("Φ.org.eolang.bool", DataType::Bool),
+    ("Φ.org.eolang.bytes", DataType::Hex),
+];
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DataType::Int => "int",
+            DataType::Float => "float",
+            DataType::String => "string",
+            DataType::Bool => "bool",
+            DataType::Hex => "hex",
+        })
+    }
+}
+
+impl FromStr for DataType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "int" => Ok(DataType::Int),
+            "float" => Ok(DataType::Float),
+            "string" => Ok(DataType::String),
+            "bool" => Ok(DataType::Bool),
+            "hex" => Ok(DataType::Hex),
+            _ => Err(anyhow!(
+                "Unknown data type '{s}', try 'int', 'float', 'string', 'bool', or 'hex'"
+            )),
+        }
+    }
+}
+
+impl DataType {
+    /// Decode the data into a human-readable text:
+    ///
+    /// ```
+    /// use reo::DataType;
+    /// use sodg::Hex;
+    /// assert_eq!("42", DataType::Int.decode(&Hex::from(42))?);
+    /// assert_eq!("00-00-00-00-00-00-00-2A", DataType::Hex.decode(&Hex::from(42))?);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn decode(&self, d: &Hex) -> Result<String> {
+        Ok(match self {
+            DataType::Int => d.to_i64()?.to_string(),
+            DataType::Float => format!("{:?}", d.to_f64()?),
+            DataType::String => d.to_utf8()?,
+            DataType::Bool => {
+                if d.len() != 1 {
+                    return Err(anyhow!(
+                        "There must be one byte in BOOL, while {} are here",
+                        d.len()
+                    ));
+                }
+                if d.to_bool() { "TRUE" } else { "FALSE" }.to_string()
+            }
+            DataType::Hex => d.print(),
+        })
+    }
+
+    /// Make a JSON object with the type, the bytes, and the decoded value:
+    ///
+    /// ```
+    /// use reo::DataType;
+    /// use sodg::Hex;
+    /// assert_eq!(
+    ///     "{\"type\":\"bool\",\"bytes\":\"01\",\"value\":true}",
+    ///     DataType::Bool.json(&Hex::from(true))?
+    /// );
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn json(&self, d: &Hex) -> Result<String> {
+        let value = match self {
+            DataType::Int => d.to_i64()?.to_string(),
+            DataType::Float => {
+                let f = self.decode(d)?;
+                if d.to_f64()?.is_finite() {
+                    f
+                } else {
+                    format!("\"{f}\"")
+                }
+            }
+            DataType::Bool => self.decode(d)?.to_lowercase(),
+            _ => format!("\"{}\"", escape(self.decode(d)?.as_str())),
+        };
+        Ok(format!(
+            "{{\"type\":\"{self}\",\"bytes\":\"{}\",\"value\":{value}}}",
+            d.print()
+        ))
+    }
+}
+
+impl Universe {
+    /// Infer the type of the data found by the last dataization.
+    /// The vertices the data was looked for in are taken, from the last
+    /// one to the first one, and for each of them, its `π` and `φ` are
+    /// followed, until one of `org.eolang.int`, `org.eolang.float`,
+    /// `org.eolang.string`, `org.eolang.bool`, or `org.eolang.bytes`
+    /// is met. If none of them is met, `None` is returned.
+    ///
+    /// Nothing is dataized again, so atoms are not called.
+    pub fn data_type(&self) -> Option<DataType> {
+        let paths = self.paths();
+        let mut seen = HashSet::new();
+        for start in self.trail.iter().rev() {
+            let mut todo = VecDeque::from([*start]);
+            while let Some(v) = todo.pop_front() {
+                if !seen.insert(v) {
+                    continue;
+                }
+                if let Some(path) = paths.get(&v) {
+                    if let Some((_, t)) = OBJECTS.iter().find(|(p, _)| p == path) {
+                        return Some(*t);
+                    }
+                }
+                todo.extend(["π", "φ"].iter().filter_map(|a| self.g.kid(v, a)));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+use crate::scripts::copy_of_int;
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+use std::rc::Rc;
+
+/// Make a Universe with `Φ.org.eolang.int` and `Φ.foo`, which
+/// is a copy of `42`, decorated by `Φ.bar`.
+#[cfg(test)]
+fn decorated_int() -> Result<Universe> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let org = uni.add()?;
+    uni.bind(root, org, "org")?;
+    let eolang = uni.add()?;
+    uni.bind(org, eolang, "eolang")?;
+    let int = uni.add()?;
+    uni.bind(eolang, int, "int")?;
+    let bar = copy_of_int(&mut uni, 42)?;
+    uni.bind(root, bar, "bar")?;
+    let foo = uni.add()?;
+    uni.bind(root, foo, "foo")?;
+    uni.bind(foo, bar, "φ")?;
+    Ok(uni)
+}
+
+#[test]
+fn infers_type_through_phi() -> Result<()> {
+    let mut uni = decorated_int()?;
+    let d = uni.dataize("Φ.foo")?;
+    let t = uni.data_type();
+    assert_eq!(Some(DataType::Int), t);
+    assert_eq!("42", t.unwrap().decode(&d)?);
+    Ok(())
+}
+
+#[test]
+fn infers_type_of_atom_result() -> Result<()> {
+    let mut uni = decorated_int()?;
+    let calls = Rc::new(RefCell::new(0));
+    let c = calls.clone();
+    uni.register("answer", move |uni: &mut Universe, _| {
+        *c.borrow_mut() += 1;
+        copy_of_int(uni, 42)
+    });
+    let baz = uni.add()?;
+    uni.bind(0, baz, "baz")?;
+    let lv = uni.add()?;
+    uni.put(lv, Hex::from_str_bytes("answer"))?;
+    uni.bind(baz, lv, "λ")?;
+    assert_eq!(42, uni.dataize("Φ.baz")?.to_i64()?);
+    assert_eq!(Some(DataType::Int), uni.data_type());
+    assert_eq!(1, *calls.borrow());
+    Ok(())
+}
+
+#[test]
+fn infers_no_type_of_plain_data() -> Result<()> {
+    let mut uni = decorated_int()?;
+    let baz = uni.add()?;
+    uni.bind(0, baz, "baz")?;
+    let d = uni.add()?;
+    uni.put(d, Hex::from(7))?;
+    uni.bind(baz, d, "Δ")?;
+    assert_eq!(7, uni.dataize("Φ.baz")?.to_i64()?);
+    assert_eq!(None, uni.data_type());
+    Ok(())
+}
+
+#[test]
+fn parses_and_prints_types() -> Result<()> {
+    for t in ["int", "float", "string", "bool", "hex"] {
+        assert_eq!(t, DataType::from_str(t)?.to_string());
+    }
+    assert!(DataType::from_str("auto").is_err());
+    Ok(())
+}
+
+#[test]
+fn makes_json() -> Result<()> {
+    assert_eq!(
+        "{\"type\":\"string\",\"bytes\":\"22-0A\",\"value\":\"\\\"\\u000a\"}",
+        DataType::String.json(&Hex::from_str_bytes("\"\n"))?
+    );
+    assert_eq!(
+        "{\"type\":\"float\",\"bytes\":\"40-09-00-00-00-00-00-00\",\"value\":3.125}",
+        DataType::Float.json(&Hex::from(3.125))?
+    );
+    assert_eq!(
+        "{\"type\":\"hex\",\"bytes\":\"--\",\"value\":\"--\"}",
+        DataType::Hex.json(&Hex::empty())?
+    );
+    assert!(DataType::Int.json(&Hex::from(true)).is_err());
+    Ok(())
+}
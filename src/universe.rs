@@ -1,34 +1,38 @@
 // SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
 // SPDX-License-Identifier: MIT
 
-use crate::{Atom, Universe};
+use crate::snapshots::Recorder;
+use crate::{Atom, Budget, Change, DotSink, Event, Hook, ReoError, SnapshotSink, Universe};
 use anyhow::{anyhow, Context, Result};
-use lazy_static::lazy_static;
-use log::{debug, trace};
-use regex::Regex;
+use log::trace;
 use sodg::Sodg;
-use sodg::{Hex, Relay};
+use sodg::{DeadRelay, Hex, Relay};
+use std::cell::RefCell;
 use std::cmp;
 use std::collections::HashMap;
-use std::fs;
-use std::fs::OpenOptions;
+use std::io;
 use std::io::prelude::*;
-use std::path::{Path, PathBuf};
+use std::io::BufReader;
+use std::path::Path;
+use std::rc::Rc;
 use std::str::FromStr;
 
 macro_rules! enter {
-    ($self:expr, $($arg:tt)+) => {
-        $self.enter_it(format!($($arg)+))?;
+    ($self:expr, $op:expr, $v:expr, $psi:expr, $loc:expr, $($arg:tt)+) => {
+        $self.enter_it($op, $v, $psi, $loc, format!($($arg)+))?;
     }
 }
 
 macro_rules! exit {
-    ($self:expr, $($arg:tt)+) => {
-        $self.exit_it(format!($($arg)+))?;
+    ($self:expr, $op:expr, $r:expr, $($arg:tt)+) => {
+        $self.exit_it($op, $r, format!($($arg)+))?;
     }
 }
 
-const MAX_RECURSION_DEPTH: usize = 20;
+const MAX_DEPTH: usize = 256;
+const MAX_STEPS: usize = 100_000;
+const MAX_VERTICES: usize = 1_000_000;
+const GC_THRESHOLD: usize = 100_000;
 
 impl Universe {
     /// Makes an empty Universe.
@@ -39,7 +43,7 @@ impl Universe {
             for v in vx.iter() {
                 let attrs = g
                     .kids(*v)
-                    .unwrap()
+                    .unwrap_or_default()
                     .iter()
                     .filter(|(a, _)| a == "π" || a == "φ")
                     .count();
@@ -54,64 +58,201 @@ impl Universe {
 
     /// Makes a Universe from a graph.
     pub fn from_graph(g: Sodg) -> Self {
+        let size = g.len();
         Universe {
             g,
             atoms: HashMap::new(),
             depth: 0,
+            steps: 0,
+            vertices: 0,
+            nesting: 0,
+            max_depth: MAX_DEPTH,
+            max_steps: MAX_STEPS,
+            max_vertices: MAX_VERTICES,
+            stack: vec![],
+            gc_threshold: GC_THRESHOLD,
+            gc_next: size + GC_THRESHOLD,
+            hooks: vec![],
+            fault: None,
+            memo: Some(HashMap::new()),
+            memo_top: 0,
+            calls: 0,
+            trail: vec![],
             snapshots: None,
+            stdout: Rc::new(RefCell::new(io::stdout())),
+            stdin: Rc::new(RefCell::new(BufReader::new(io::stdin()))),
         }
     }
 
-    /// Point it to snapshots directory.
+    /// Point it to snapshots directory, where they will be saved
+    /// as `.dot` files, see [`DotSink`].
     pub fn with_snapshots(&self, p: &Path) -> Self {
-        Universe {
-            g: self.g.clone(),
-            atoms: self.atoms.clone(),
-            depth: self.depth,
-            snapshots: Some(p.as_os_str().to_str().unwrap().to_string()),
-        }
+        self.clone()
+            .with_snapshot_sink(Rc::new(RefCell::new(DotSink::new(p))))
+    }
+
+    /// Take a snapshot of the graph at every step of dataization
+    /// and give it to the sink.
+    pub fn with_snapshot_sink(mut self, sink: Rc<RefCell<dyn SnapshotSink>>) -> Self {
+        self.snapshots = Some(Recorder::new(sink, &mut self.g));
+        self
+    }
+
+    /// Limit the depth of recursion of a dataization (256 by default).
+    pub fn with_max_depth(mut self, max: usize) -> Self {
+        self.max_depth = max;
+        self
+    }
+
+    /// Limit the number of `apply` calls in one dataization
+    /// (100K by default).
+    pub fn with_max_steps(mut self, max: usize) -> Self {
+        self.max_steps = max;
+        self
+    }
+
+    /// Limit the number of vertices one dataization may create
+    /// (1M by default).
+    pub fn with_max_vertices(mut self, max: usize) -> Self {
+        self.max_vertices = max;
+        self
+    }
+
+    /// Run the GC automatically every time this many vertices are
+    /// added to the graph since the last run (100K by default),
+    /// or never, if it's zero. The GC only runs before a new
+    /// dataization starts, never in the middle of one.
+    pub fn with_gc_threshold(mut self, n: usize) -> Self {
+        self.gc_threshold = n;
+        self.gc_next = self.g.len() + n;
+        self
+    }
+
+    /// Turn memoization on or off (it is on by default).
+    ///
+    /// When it's on, the data found for an object is remembered,
+    /// unless an atom was called while finding it. Next time,
+    /// the object is not dataized again and the graph doesn't grow.
+    pub fn with_memo(mut self, on: bool) -> Self {
+        self.memo = on.then(HashMap::new);
+        self.memo_top = 0;
+        self
+    }
+
+    /// Redirect the output of the atoms, like `org.eolang.io.stdout`,
+    /// to the given writer, instead of the standard output.
+    pub fn with_stdout(mut self, out: Rc<RefCell<dyn Write>>) -> Self {
+        self.stdout = out;
+        self
+    }
+
+    /// Make the atoms, like `org.eolang.io.stdin`, read from the
+    /// given reader, instead of the standard input.
+    pub fn with_stdin(mut self, input: Rc<RefCell<dyn BufRead>>) -> Self {
+        self.stdin = input;
+        self
+    }
+
+    /// Add a hook, which will be called at every step of dataization.
+    pub fn with_hook(mut self, hook: Rc<RefCell<dyn Hook>>) -> Self {
+        self.hooks.push(hook);
+        self
     }
 
-    /// Registers a new atom.
-    pub fn register(&mut self, name: &str, a: Atom) {
-        self.atoms.insert(name.to_string(), a);
-        trace!("#register: atom {name} registered as {:p}", a as *const ());
+    /// Registers a new atom, which may be a plain function or
+    /// a closure that captures the services of the host.
+    pub fn register<A: Atom + 'static>(&mut self, name: &str, a: A) {
+        self.atoms.insert(name.to_string(), Rc::new(a));
+        trace!("#register: atom {name} registered");
     }
 
     /// Add new vertex and return its ID.
-    pub fn add(&mut self) -> u32 {
+    pub fn add(&mut self) -> Result<u32> {
         let v = self.g.next_id();
-        self.g
-            .add(v)
-            .context(anyhow!("Failed to add ν{v}"))
-            .unwrap();
-        v
+        self.g_add(v).context(anyhow!("Failed to add ν{v}"))?;
+        self.vertices += 1;
+        Ok(v)
     }
 
-    /// Bind two new vertices.
-    pub fn bind(&mut self, v1: u32, v2: u32, a: &str) {
-        self.g
-            .bind(v1, v2, a)
+    /// Bind two new vertices. If any of them is absent,
+    /// an error is returned.
+    pub fn bind(&mut self, v1: u32, v2: u32, a: &str) -> Result<()> {
+        self.forget(v1);
+        self.g_bind(v1, v2, a)
             .context(anyhow!("Failed to bind ν{v1} to ν{v2} as '{a}'"))
-            .unwrap();
     }
 
-    /// Save data into a vertex. If there is no vertex `v`, the function
-    /// will panic.
-    pub fn put(&mut self, v: u32, d: Hex) {
+    /// Save data into a vertex. If there is no vertex `v`,
+    /// an error is returned.
+    pub fn put(&mut self, v: u32, d: Hex) -> Result<()> {
+        self.forget(v);
         self.g
             .put(v, &d)
-            .context(anyhow!("Failed to put the data to ν{v}"))
-            .unwrap();
+            .context(anyhow!("Failed to put the data to ν{v}"))?;
+        if let Some(rec) = self.snapshots.as_mut() {
+            rec.note(Change::Put(v, d));
+        }
+        Ok(())
     }
 
-    /// Get the `Hex` from the vertex.
-    /// If there is no vertex `v`, the function will panic.
-    pub fn data(&mut self, v: u32) -> Hex {
+    /// Get the `Hex` from the vertex. If there is no vertex `v`,
+    /// an error is returned.
+    pub fn data(&mut self, v: u32) -> Result<Hex> {
         self.g
             .data(v)
             .context(anyhow!("Failed to get data from ν{v}"))
-            .unwrap()
+    }
+
+    /// Get all kids of the vertex `v`, with their attributes.
+    pub fn kids(&self, v: u32) -> Result<Vec<(String, u32)>> {
+        self.g
+            .kids(v)
+            .context(anyhow!("Failed to get kids of ν{v}"))
+    }
+
+    /// Get the kid of the vertex `v` by the attribute `a`, if it exists.
+    pub fn kid(&self, v: u32, a: &str) -> Option<u32> {
+        self.g.kid(v, a)
+    }
+
+    /// Print the text to the output of the Universe.
+    pub fn print(&mut self, text: &str) -> Result<()> {
+        let mut out = self.stdout.borrow_mut();
+        out.write_all(text.as_bytes())
+            .context(anyhow!("Failed to print {} bytes", text.len()))?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Read the next line from the input of the Universe, without the
+    /// trailing end-of-line. Returns `None` at the end of the input.
+    pub fn read_line(&mut self) -> Result<Option<String>> {
+        let mut line = String::new();
+        let bytes = self
+            .stdin
+            .borrow_mut()
+            .read_line(&mut line)
+            .context("Failed to read a line from the input")?;
+        if bytes == 0 {
+            return Ok(None);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
+    }
+
+    /// Read everything that is left in the input of the Universe.
+    pub fn read_all(&mut self) -> Result<String> {
+        let mut all = String::new();
+        self.stdin
+            .borrow_mut()
+            .read_to_string(&mut all)
+            .context("Failed to read the input")?;
+        Ok(all)
     }
 
     /// Dataize by absolute locator. The search always starts from the
@@ -122,10 +263,19 @@ impl Universe {
         let v = self
             .find(format!("{loc}.Δ").as_str())
             .context(format!("Can't find {loc}"))?;
-        let data = self
-            .g
-            .data(v)
-            .context(format!("There is no data in {}", self.g.v_print(v)?))?;
+        if self.nesting == 0 && self.trail.is_empty() {
+            // The data was reached by the edges only, without the relay,
+            // so the object is found the same way, for the sake of
+            // Universe::data_type
+            let rel = loc.strip_prefix("Φ.").unwrap_or(loc);
+            if let Ok(obj) = self.g.find(0, rel, &DeadRelay {}) {
+                self.trail.push(obj);
+            }
+        }
+        let data = self.g.data(v).map_err(|_| ReoError::NoData {
+            v,
+            loc: loc.to_string(),
+        })?;
         trace!(
             "#dataize: data found in ν{v} ({} bytes): {}",
             data.len(),
@@ -137,14 +287,41 @@ impl Universe {
     /// Find vertex by absolute locator. The search always starts from the
     /// root node of the tree. It is recommended to start the locator
     /// from "Φ".
+    ///
+    /// Every call that is not made from inside of an atom starts
+    /// a new dataization, with all budgets renewed.
     pub fn find(&mut self, loc: &str) -> Result<u32> {
         if self.g.is_empty() {
             return Err(anyhow!("The Universe is empty, can't dataize {loc}"));
         }
-        let v = self
-            .g
-            .find(0, loc, self)
-            .context(format!("Failed to find {loc}"))?;
+        if self.nesting == 0 {
+            self.depth = 0;
+            self.steps = 0;
+            self.vertices = 0;
+            self.stack.clear();
+            self.trail.clear();
+            self.fault = None;
+            self.gc_if_needed()?;
+        }
+        self.nesting += 1;
+        let r = self.g.find(0, loc, self);
+        self.nesting -= 1;
+        if self.nesting == 0 {
+            if let Some(rec) = self.snapshots.as_mut() {
+                rec.flush()?;
+            }
+        }
+        let v = match r {
+            Ok(v) => v,
+            Err(e) => {
+                return Err(match self.fault.take() {
+                    Some(fault) => anyhow::Error::new(fault),
+                    None => e,
+                }
+                .context(format!("Failed to find {loc}")))
+            }
+        };
+        self.fault = None;
         Ok(v)
     }
 
@@ -184,56 +361,152 @@ impl Universe {
         if a == "Φ" {
             return Ok("ν0".to_string());
         };
-        let v1 = uni.fnd(v, a, 0)?;
+        if a == "Δ" {
+            if let Some(to) = uni.memo.as_ref().and_then(|m| m.get(&v)).copied() {
+                trace!("#re: ν{v}.Δ is memoized as ν{to}");
+                uni.track(v);
+                return Ok(format!("ν{to}"));
+            }
+        }
+        let calls = uni.calls;
+        let v1 = uni.fnd(v, a, 0).inspect_err(|e| uni.remember(e))?;
+        // Every `λ` met on the path is called in `pf`, which counts the
+        // calls, so if the counter didn't move, there was no `λ` on the
+        // path and the data may be remembered.
+        if a == "Δ" && uni.calls == calls {
+            if let Some(m) = uni.memo.as_mut() {
+                m.insert(v, v1);
+                uni.memo_top = cmp::max(uni.memo_top, uni.g.next_id());
+            }
+        }
         Ok(format!("ν{v1}"))
     }
 
+    /// Remember that the data is looked for in this vertex, if it's
+    /// not an atom that is dataizing something on its own.
+    fn track(&mut self, v: u32) {
+        if self.nesting == 1 {
+            self.trail.push(v);
+        }
+    }
+
+    /// Drop everything memoized, if the vertex may be seen there,
+    /// because it is going to be modified.
+    fn forget(&mut self, v: u32) {
+        if v < self.memo_top {
+            if let Some(m) = self.memo.as_mut() {
+                trace!(
+                    "#forget: ν{v} is modified, {} memoized objects dropped",
+                    m.len()
+                );
+                m.clear();
+            }
+            self.memo_top = 0;
+        }
+    }
+
+    /// Remember the error, if it's a [`ReoError`] and nothing is
+    /// remembered yet, so that [`Universe::find`] can return it instead
+    /// of the string that the graph makes of it.
+    fn remember(&mut self, e: &anyhow::Error) {
+        if self.fault.is_none() {
+            self.fault = e.downcast_ref::<ReoError>().cloned();
+        }
+    }
+
     /// Find.
     fn fnd(&mut self, v: u32, a: &str, psi: u32) -> Result<u32> {
-        enter!(self, "#fnd(ν{v}, {a}, {psi}): entered...");
+        enter!(
+            self,
+            "fnd",
+            v,
+            psi,
+            format!("ν{v}.{a}"),
+            "#fnd(ν{v}, {a}, {psi}): entered..."
+        );
         let v1 = self.dd(v, psi)?;
+        if a == "Δ" {
+            self.track(v);
+            self.track(v1);
+        }
         let to = self.pf(v1, a, psi)?;
-        exit!(self, "#fnd(ν{v}, {a}, {psi}): pf(ν{v}, {a}) returned ν{to}");
+        exit!(
+            self,
+            "fnd",
+            to,
+            "#fnd(ν{v}, {a}, {psi}): pf(ν{v}, {a}) returned ν{to}"
+        );
         Ok(to)
     }
 
     /// Path find.
     fn pf(&mut self, v: u32, a: &str, psi: u32) -> Result<u32> {
-        enter!(self, "#pf(ν{v}, {a}, {psi}): entering...");
-        let r = if let Some(to) = self.g.kid(v, a) {
-            to
-        } else if let Some(lv) = self.g.kid(v, "λ") {
-            let lambda = self.g.data(lv)?.to_utf8()?;
-            trace!("#re: calling ν{v}.λ⇓{lambda}(ξ=ν?)...");
-            let to = self
-                .atoms
-                .get(lambda.as_str())
-                .context(anyhow!(
-                    "Can't find function {lambda} among {} others",
-                    self.atoms.len()
-                ))
-                .unwrap()(self, v)?;
-            trace!("#re: ν{v}.λ⇓{lambda}(ξ=ν?) returned ν{to}");
-            self.fnd(to, a, psi)?
-        } else if let Some(to) = self.g.kid(v, "φ") {
-            self.fnd(to, a, psi)?
-        } else if let Some(to) = self.g.kid(v, "γ") {
-            let t = Self::fnd(self, to, a, psi)?;
-            self.g.bind(v, t, a)?;
-            t
-        } else {
-            return Err(anyhow!(
-                "There is no way to get .{a} from {}",
-                self.g.v_print(v)?
-            ));
-        };
-        exit!(self, "#pf(ν{v}, {a}, {psi}): returning ν{}", r);
+        enter!(
+            self,
+            "pf",
+            v,
+            psi,
+            format!("ν{v}.{a}"),
+            "#pf(ν{v}, {a}, {psi}): entering..."
+        );
+        let r =
+            if let Some(to) = self.g.kid(v, a) {
+                to
+            } else if let Some(lv) = self.g.kid(v, "λ") {
+                let lambda = self.g.data(lv)?.to_utf8()?;
+                self.calls += 1;
+                let atom = self.atoms.get(lambda.as_str()).cloned().ok_or_else(|| {
+                    ReoError::MissingAtom {
+                        v,
+                        loc: format!("ν{v}.{a}"),
+                        atom: lambda.clone(),
+                    }
+                })?;
+                trace!("#re: calling ν{v}.λ⇓{lambda}(ξ=ν?) with {}...", atom.name());
+                let to = atom
+                    .call(self, v)
+                    .map_err(|e| match e.downcast::<ReoError>() {
+                        Ok(r) => r,
+                        Err(e) => ReoError::AtomFailure {
+                            v,
+                            loc: format!("ν{v}.{a}"),
+                            atom: lambda.clone(),
+                            cause: format!("{e:#}"),
+                        },
+                    })?;
+                trace!("#re: ν{v}.λ⇓{lambda}(ξ=ν?) returned ν{to}");
+                self.fnd(to, a, psi)?
+            } else if let Some(to) = self.g.kid(v, "φ") {
+                self.fnd(to, a, psi)?
+            } else if let Some(to) = self.g.kid(v, "γ") {
+                let t = Self::fnd(self, to, a, psi)?;
+                self.g_bind(v, t, a)?;
+                t
+            } else {
+                trace!(
+                    "#pf: there is no way to get .{a} from {}",
+                    self.g.v_print(v)?
+                );
+                return Err(ReoError::AttributeNotFound {
+                    v,
+                    loc: format!("ν{v}.{a}"),
+                }
+                .into());
+            };
+        exit!(self, "pf", r, "#pf(ν{v}, {a}, {psi}): returning ν{}", r);
         Ok(r)
     }
 
     /// Dynamic dispatch.
     fn dd(&mut self, v: u32, psi: u32) -> Result<u32> {
-        enter!(self, "#dd(ν{v}, {psi}): entering...");
+        enter!(
+            self,
+            "dd",
+            v,
+            psi,
+            format!("ν{v}"),
+            "#dd(ν{v}, {psi}): entering..."
+        );
         let psi2 = match self.g.kid(v, "ψ") {
             Some(p) => p,
             None => psi,
@@ -253,25 +526,46 @@ impl Universe {
             self.dd(nv, psi2)?
         } else if let Some(to) = self.g.kid(v, "π") {
             let nv = self.dd(to, psi2)?;
-            self.apply(nv, v)?
+            self.apply(nv, v, psi2)?
         } else {
             v
         };
-        exit!(self, "#dd(ν{v}, {psi}): returning ν{}", r);
+        exit!(self, "dd", r, "#dd(ν{v}, {psi}): returning ν{}", r);
         Ok(r)
     }
 
-    /// Apply `v1` to `v2` and return a new vertex.
-    fn apply(&mut self, v1: u32, v2: u32) -> Result<u32> {
-        enter!(self, "#apply(ν{v1}, ν{v2}): entering...");
-        self.depth += 1;
+    /// Apply `v1` to `v2` and return a new vertex. The `psi` is the
+    /// object, where `v2` is met, its arguments are bound to it.
+    fn apply(&mut self, v1: u32, v2: u32, psi: u32) -> Result<u32> {
+        enter!(
+            self,
+            "apply",
+            v1,
+            psi,
+            format!("ν{v1}"),
+            "#apply(ν{v1}, ν{v2}, {psi}): entering..."
+        );
+        self.steps += 1;
+        if self.steps > self.max_steps {
+            return Err(ReoError::RecursionLimit {
+                v: v1,
+                loc: format!("ν{v1}"),
+                budget: Budget::Steps,
+                used: self.steps,
+                max: self.max_steps,
+            }
+            .into());
+        }
         let nv = self.g.next_id();
-        self.g.add(nv)?;
+        self.g_add(nv)?;
+        self.vertices += 1;
         self.pull(nv, v1)?;
-        self.push(nv, v2)?;
+        self.push(nv, v2, psi)?;
         exit!(
             self,
-            "#apply(ν{v1}, ν{v2}): copy ν{v1}+ν{v2} created as ν{nv}"
+            "apply",
+            nv,
+            "#apply(ν{v1}, ν{v2}, {psi}): copy ν{v1}+ν{v2} created as ν{nv}"
         );
         Ok(nv)
     }
@@ -288,39 +582,66 @@ impl Universe {
     }
 
     /// Link.
+    ///
+    /// Only an abstract object gets `v1` as its new `ρ`. An expression,
+    /// like `x.if(..)`, keeps the `ρ` it is computed with.
     fn up(&mut self, v1: u32, v2: u32, a: String) -> Result<()> {
         if a == "λ" || a == "Δ" || a == "ρ" || self.nil(v2)? {
-            self.g.bind(v1, v2, a.as_str())?;
+            self.g_bind(v1, v2, a.as_str())?;
         } else {
             let nv = self.g.next_id();
-            self.g.add(nv)?;
-            self.g.bind(v1, nv, a.as_str())?;
-            self.g.bind(nv, v1, "ρ")?;
-            self.g.bind(nv, v1, "ψ")?;
-            self.g.bind(nv, v2, "π")?;
+            self.g_add(nv)?;
+            self.vertices += 1;
+            self.g_bind(v1, nv, a.as_str())?;
+            if ["π", "β", "ε", "ξ"].iter().all(|e| self.g.kid(v2, e).is_none()) {
+                self.g_bind(nv, v1, "ρ")?;
+            }
+            self.g_bind(nv, v1, "ψ")?;
+            self.g_bind(nv, v2, "π")?;
         };
         Ok(())
     }
 
     /// Push from `v2` to `v1`.
-    fn push(&mut self, v1: u32, v2: u32) -> Result<()> {
+    fn push(&mut self, v1: u32, v2: u32, psi: u32) -> Result<()> {
         for (a, k) in self.g.kids(v2)?.into_iter() {
             if a == "π" || a == "ψ" {
                 continue;
             }
-            self.down(v1, k, a)?;
+            self.down(v1, k, a, psi)?;
         }
         Ok(())
     }
 
     /// Link down.
-    fn down(&mut self, v1: u32, v2: u32, a: String) -> Result<()> {
-        let a1 = self.tie(v1, a)?;
-        self.g.bind(v1, v2, a1.as_str())?;
+    ///
+    /// An argument is bound through a new vertex with `ε` to it and
+    /// `ψ` to the object where it was met, because its `ξ` means that
+    /// object, not the copy it is pushed into. Without this, `f (x.minus 1)`
+    /// inside `f` would find its `x` in itself, forever. At the top of
+    /// the search, where there is no such object yet, it is bound as is.
+    fn down(&mut self, v1: u32, v2: u32, a: String, psi: u32) -> Result<()> {
+        let a1 = self.tie(v1, a.clone())?;
+        let bare = a == "ρ" || a == "σ" || a == "Δ" || a == "λ";
+        if psi == 0 || bare || self.g.kid(v2, "ψ").is_some() || self.nil(v2)? {
+            self.g_bind(v1, v2, a1.as_str())?;
+        } else {
+            let nv = self.g.next_id();
+            self.g_add(nv)?;
+            self.vertices += 1;
+            self.g_bind(v1, nv, a1.as_str())?;
+            self.g_bind(nv, v2, "ε")?;
+            self.g_bind(nv, psi, "ψ")?;
+        }
         Ok(())
     }
 
     /// Tie an existing name with a new name.
+    ///
+    /// The `αN` is tied to the N-th free attribute of the vertex, which
+    /// is an ASCII attribute pointing to a nil. If there is no such
+    /// attribute, `αN` stays as is, since it's an element of varargs,
+    /// like in `org.eolang.array`.
     fn tie(&mut self, v: u32, a: String) -> Result<String> {
         if a == "ρ" || a == "σ" {
             trace!("#tie(ν{v}, {a}): it's a direct tie");
@@ -339,177 +660,127 @@ impl Universe {
         if a.starts_with('α') {
             let tail: String = a.chars().skip(1).collect::<Vec<_>>().into_iter().collect();
             let i = usize::from_str(tail.as_str())?;
-            let a1 = self
-                .g
-                .kids(v)?
-                .into_iter()
-                .filter(|(aa, _)| aa.is_ascii())
-                .nth(i)
-                .unwrap();
-            trace!("#tie(ν{v}, {a}): the {i}th attribute is {}", a1.0);
-            return self.tie(v, a1.0);
+            let mut free = vec![];
+            for (aa, k) in self.g.kids(v)?.into_iter() {
+                if aa.is_ascii() && self.nil(k)? {
+                    free.push(aa);
+                }
+            }
+            if let Some(a1) = free.into_iter().nth(i) {
+                trace!("#tie(ν{v}, {a}): the {i}th free attribute is {a1}");
+                return self.tie(v, a1);
+            }
+            trace!("#tie(ν{v}, {a}): there is no {i}th free attribute, it's a vararg");
+            return Ok(a);
         }
-        Err(anyhow!("Can't tie to ν{v}.{a}"))
+        Err(ReoError::TieFailure {
+            v,
+            loc: format!("ν{v}.{a}"),
+        }
+        .into())
     }
 
     /// The vertex is a dead-end, a nil.
     fn nil(&mut self, v: u32) -> Result<bool> {
         let kids = self.g.kids(v)?;
-        return Ok(kids.len() == 1 && kids.iter().all(|(a, _)| a == "ρ"));
+        Ok(kids.len() == 1 && kids.iter().all(|(a, _)| a == "ρ"))
     }
 
-    fn enter_it(&mut self, msg: String) -> Result<()> {
+    fn enter_it(
+        &mut self,
+        op: &'static str,
+        v: u32,
+        psi: u32,
+        loc: String,
+        msg: String,
+    ) -> Result<()> {
         self.depth += 1;
-        if self.depth > MAX_RECURSION_DEPTH {
-            return Err(anyhow!("The recursion is too deep ({} levels)", self.depth));
+        if self.depth > self.max_depth {
+            return Err(ReoError::RecursionLimit {
+                v,
+                loc,
+                budget: Budget::Depth,
+                used: self.depth,
+                max: self.max_depth,
+            }
+            .into());
         }
-        self.snapshot(msg)?;
-        Ok(())
+        if self.vertices > self.max_vertices {
+            return Err(ReoError::RecursionLimit {
+                v,
+                loc,
+                budget: Budget::Vertices,
+                used: self.vertices,
+                max: self.max_vertices,
+            }
+            .into());
+        }
+        self.stack.push(v);
+        self.snapshot(&msg)?;
+        self.call_hooks(Event::Enter {
+            op,
+            v,
+            psi,
+            loc,
+            depth: self.depth,
+            msg,
+        })
     }
 
-    fn exit_it(&mut self, msg: String) -> Result<()> {
+    fn exit_it(&mut self, op: &'static str, result: u32, msg: String) -> Result<()> {
+        let depth = self.depth;
         if self.depth > 0 {
             self.depth -= 1;
         }
-        self.snapshot(msg)?;
-        Ok(())
+        let v = self.stack.pop().unwrap_or_default();
+        self.snapshot(&msg)?;
+        self.call_hooks(Event::Exit {
+            op,
+            v,
+            result,
+            depth,
+            msg,
+        })
     }
 
-    const COLORS: &'static str = "fillcolor=aquamarine3,style=filled,";
+    /// Tell all hooks about the event. They are taken away
+    /// while being called, in order to not be called recursively.
+    fn call_hooks(&mut self, event: Event) -> Result<()> {
+        if self.hooks.is_empty() {
+            return Ok(());
+        }
+        let hooks = std::mem::take(&mut self.hooks);
+        let r = hooks
+            .iter()
+            .try_for_each(|h| h.borrow_mut().on(self, &event));
+        self.hooks = hooks;
+        r
+    }
 
-    /// Create a new snapshot (PDF file)
-    fn snapshot(&mut self, msg: String) -> Result<()> {
-        lazy_static! {
-            static ref DOT_LINE: Regex = Regex::new("^ +v([0-9]+)\\[.*$").unwrap();
+    /// Take a snapshot of the graph, if snapshots are on.
+    fn snapshot(&mut self, msg: &str) -> Result<()> {
+        if let Some(rec) = self.snapshots.as_mut() {
+            rec.record(self.depth, msg)?;
         }
-        if self.snapshots.is_none() {
-            return Ok(());
+        Ok(())
+    }
+
+    /// Add a vertex to the graph, and let the snapshots know.
+    fn g_add(&mut self, v: u32) -> Result<()> {
+        self.g.add(v)?;
+        if let Some(rec) = self.snapshots.as_mut() {
+            rec.note(Change::Add(v));
         }
-        let p = self.snapshots.clone().unwrap();
-        let home = Path::new(&p);
-        fs::create_dir_all(home)
-            .context(anyhow!("Can't create directory {}", home.to_str().unwrap()))?;
-        let total = fs::read_dir(home)
-            .context(anyhow!("Can't list files in {}", home.to_str().unwrap()))?
-            .filter(|f| {
-                f.as_ref()
-                    .unwrap()
-                    .path()
-                    .as_os_str()
-                    .to_str()
-                    .unwrap()
-                    .ends_with(".dot")
-            })
-            .count();
-        debug!(
-            "{total} snapshot files already in {}",
-            home.to_str().unwrap()
-        );
-        if total == 0 {
-            fs::copy("surge-make/Makefile", home.join("Makefile")).context(anyhow!(
-                "Can't copy Makefile to '{}'",
-                home.to_str().unwrap()
-            ))?;
-            fs::copy("surge-make/doc.tex", home.join("doc.tex")).context(anyhow!(
-                "Can't copy doc.tex to '{}'",
-                home.to_str().unwrap()
-            ))?;
-            fs::write(home.join("list.tex"), b"").context(anyhow!("Can't write empty list.tex"))?;
-            debug!("Snapshot dir created: {}", home.to_str().unwrap());
-        }
-        let pos = total + 1;
-        let mut before = String::new();
-        if pos > 1 {
-            let fname = format!("{}.dot", pos - 1);
-            let b = home.join(fname.clone());
-            before = fs::read_to_string(b.clone())
-                .context(anyhow!(
-                    "Can't read previous {fname} file from '{}'",
-                    home.to_str().unwrap()
-                ))?
-                .replace(Self::COLORS, "");
-            debug!("Previous snapshot read from: {}", Self::fprint(b));
-        }
-        let seen: Vec<u32> = before
-            .split('\n')
-            .map(|t| match &DOT_LINE.captures(t) {
-                Some(m) => m.get(1).unwrap().as_str().parse().unwrap(),
-                None => 0,
-            })
-            .collect();
-        let dot = self.g.to_dot();
-        let dot_file = home.join(format!("{pos}.dot"));
-        fs::write(
-            &dot_file,
-            dot.split('\n')
-                .map(|t| match &DOT_LINE.captures(t) {
-                    Some(m) => {
-                        let v = m.get(1).unwrap().as_str().parse::<u32>().unwrap();
-                        if seen.contains(&v) {
-                            t.to_string()
-                        } else {
-                            t.replace('[', format!("[{}", Self::COLORS).as_str())
-                        }
-                    }
-                    None => t.to_string(),
-                })
-                .collect::<Vec<String>>()
-                .join("\n"),
-        )?;
-        debug!("Dot file saved: {}", Self::fprint(dot_file.clone()));
-        if dot == before {
-            if pos > 0 {
-                let m = Self::fprint(dot_file.clone());
-                fs::remove_file(dot_file.clone()).context(anyhow!(
-                    "Can't remove previous .dot file {}",
-                    dot_file.to_str().unwrap()
-                ))?;
-                debug!("Similar dot file removed: {m}");
-            }
-        } else {
-            let mut list = OpenOptions::new()
-                .append(true)
-                .open(home.join("list.tex"))
-                .context(anyhow!(
-                    "Can't open {}/list.tex for appending",
-                    home.to_str().unwrap()
-                ))?;
-            writeln!(list, "\\graph{{{pos}}}")?;
-        }
-        let mut log = OpenOptions::new()
-            .append(true)
-            .create(true)
-            .open(home.join("log.txt"))
-            .context(anyhow!(
-                "Can't open {}/log.txt for writing",
-                home.to_str().unwrap()
-            ))?;
-        writeln!(
-            log,
-            "{}{}",
-            "  ".repeat(self.depth),
-            msg.replace('ν', "v").replace('Δ', "D")
-        )?;
-        let full = fs::read_to_string(home.join("log.txt"))?;
-        let lines = full.split('\n').collect::<Vec<&str>>();
-        let max = 32;
-        fs::write(
-            home.join(format!("log-{pos}.txt")),
-            lines
-                .clone()
-                .into_iter()
-                .skip(cmp::max(0i16, lines.len() as i16 - max) as usize)
-                .collect::<Vec<&str>>()
-                .join("\n"),
-        )?;
-        debug!("Log #{pos} added (lines={})", lines.len());
         Ok(())
     }
 
-    /// Turn file name into a better visible string, for logs.
-    fn fprint(f: PathBuf) -> String {
-        let size = f.metadata().unwrap().len();
-        format!("{} ({size} bytes)", f.to_str().unwrap())
+    /// Bind two vertices in the graph, and let the snapshots know.
+    fn g_bind(&mut self, v1: u32, v2: u32, a: &str) -> Result<()> {
+        self.g.bind(v1, v2, a)?;
+        if let Some(rec) = self.snapshots.as_mut() {
+            rec.note(Change::Bind(v1, v2, a.to_string()));
+        }
+        Ok(())
     }
 }
 
@@ -517,48 +788,106 @@ impl Universe {
 use sodg::Script;
 
 #[cfg(test)]
-use std::process::Command;
+use std::fs;
+
+#[cfg(test)]
+use crate::HtmlSink;
 
 #[cfg(test)]
 use glob::glob;
 
 #[cfg(test)]
-fn rand(uni: &mut Universe, _: u32) -> Result<u32> {
-    let v = uni.add();
-    uni.bind(v, 0, "π/int");
-    let v2 = uni.add();
-    uni.bind(v, v2, "Δ");
-    uni.put(v2, Hex::from(rand::random::<i64>()));
-    Ok(v)
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Make an atom that generates random integers with its own
+/// seeded generator.
+#[cfg(test)]
+fn rand(seed: u64) -> impl Fn(&mut Universe, u32) -> Result<u32> {
+    let rng = RefCell::new(StdRng::seed_from_u64(seed));
+    move |uni, _| {
+        let v = uni.add()?;
+        uni.bind(v, 0, "π/int")?;
+        let v2 = uni.add()?;
+        uni.bind(v, v2, "Δ")?;
+        uni.put(v2, Hex::from(rng.borrow_mut().gen::<i64>()))?;
+        Ok(v)
+    }
 }
 
 #[test]
 fn generates_random_int() -> Result<()> {
     let mut uni = Universe::empty();
-    let root = uni.add();
+    let root = uni.add()?;
     assert_eq!(0, root);
-    let v1 = uni.add();
-    uni.bind(root, v1, "int");
-    let v2 = uni.add();
-    uni.bind(root, v2, "rand");
-    uni.bind(root, v2, "x");
-    uni.register("rand", rand);
-    let lambda = uni.add();
-    uni.bind(v2, lambda, "λ");
-    uni.put(lambda, Hex::from_str_bytes("rand"));
+    let v1 = uni.add()?;
+    uni.bind(root, v1, "int")?;
+    let v2 = uni.add()?;
+    uni.bind(root, v2, "rand")?;
+    uni.bind(root, v2, "x")?;
+    uni.register("rand", rand(42));
+    let lambda = uni.add()?;
+    uni.bind(v2, lambda, "λ")?;
+    uni.put(lambda, Hex::from_str_bytes("rand"))?;
     let first = uni.dataize("Φ.x")?.to_i64()?;
     let second = uni.dataize("Φ.x")?.to_i64()?;
     assert_ne!(first, second);
     Ok(())
 }
 
+#[test]
+fn keeps_state_of_closure_between_dataizations() -> Result<()> {
+    let mut uni = Universe::empty();
+    let root = uni.add()?;
+    let v1 = uni.add()?;
+    uni.bind(root, v1, "next")?;
+    let lambda = uni.add()?;
+    uni.bind(v1, lambda, "λ")?;
+    uni.put(lambda, Hex::from_str_bytes("next"))?;
+    let counter = Rc::new(RefCell::new(0));
+    let seen = counter.clone();
+    uni.register("next", move |uni: &mut Universe, _: u32| {
+        *counter.borrow_mut() += 1;
+        let v = uni.add()?;
+        let d = uni.add()?;
+        uni.bind(v, d, "Δ")?;
+        uni.put(d, Hex::from(*counter.borrow()))?;
+        Ok(v)
+    });
+    assert_eq!(1, uni.dataize("Φ.next")?.to_i64()?);
+    assert_eq!(2, uni.dataize("Φ.next")?.to_i64()?);
+    assert_eq!(2, *seen.borrow());
+    Ok(())
+}
+
+/// An atom with its own name, which always returns `ν0`.
+#[cfg(test)]
+struct Named;
+
+#[cfg(test)]
+impl Atom for Named {
+    fn call(&self, _: &mut Universe, _: u32) -> Result<u32> {
+        Ok(0)
+    }
+
+    fn name(&self) -> &str {
+        "named"
+    }
+}
+
+#[test]
+fn names_atoms() {
+    assert_eq!("named", Named.name());
+    assert_eq!("reo::universe::inc", Atom::name(&inc));
+    assert!(rand(42).name().contains("closure"), "{}", rand(42).name());
+}
+
 #[cfg(test)]
 fn inc(uni: &mut Universe, v: u32) -> Result<u32> {
     let rho = uni.dataize(format!("ν{v}.ρ").as_str())?.to_i64()?;
-    let v1 = uni.add();
-    let v2 = uni.add();
-    uni.bind(v1, v2, "Δ");
-    uni.put(v2, Hex::from(rho + 1));
+    let v1 = uni.add()?;
+    let v2 = uni.add()?;
+    uni.bind(v1, v2, "Δ")?;
+    uni.put(v2, Hex::from(rho + 1))?;
     Ok(v1)
 }
 
@@ -566,10 +895,10 @@ fn inc(uni: &mut Universe, v: u32) -> Result<u32> {
 fn times(uni: &mut Universe, v: u32) -> Result<u32> {
     let rho = uni.dataize(format!("ν{v}.ρ").as_str())?.to_i64()?;
     let x = uni.dataize(format!("ν{v}.x").as_str())?.to_i64()?;
-    let v1 = uni.add();
-    let v2 = uni.add();
-    uni.bind(v1, v2, "Δ");
-    uni.put(v2, Hex::from(rho * x));
+    let v1 = uni.add()?;
+    let v2 = uni.add()?;
+    uni.bind(v1, v2, "Δ")?;
+    uni.put(v2, Hex::from(rho * x))?;
     Ok(v1)
 }
 
@@ -592,7 +921,7 @@ fn sodg_scripts_in_dir(dir: &str) -> Vec<String> {
 fn find_absent_vertex() -> Result<()> {
     let g = Sodg::empty();
     let mut uni = Universe::from_graph(g);
-    uni.add();
+    uni.add()?;
     assert!(uni.dataize("Φ.foo").is_err());
     Ok(())
 }
@@ -601,7 +930,7 @@ fn find_absent_vertex() -> Result<()> {
 fn fnd_absent_vertex() -> Result<()> {
     let g = Sodg::empty();
     let mut uni = Universe::from_graph(g);
-    uni.add();
+    uni.add()?;
     assert!(uni.dataize("ν42.foo").is_err());
     Ok(())
 }
@@ -627,6 +956,216 @@ fn fails_on_phi_cycle() -> Result<()> {
             .any(|e| e.to_string().contains("The recursion is too deep")),
         "Unexpected error: {err}"
     );
+    assert!(
+        matches!(
+            err.downcast_ref::<ReoError>(),
+            Some(ReoError::RecursionLimit {
+                budget: Budget::Depth,
+                ..
+            })
+        ),
+        "Unexpected error: {err:?}"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+fn copy_of_inc() -> Result<Universe> {
+    let mut s = Script::from_str(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, integer);
+        ADD($ν2);
+        BIND($ν1, $ν2, inc);
+        BIND($ν2, $ν1, ρ);
+        ADD($ν3);
+        BIND($ν2, $ν3, λ);
+        PUT($ν3, 69-6E-63);
+        ADD($ν4);
+        BIND($ν4, $ν1, π);
+        ADD($ν5);
+        BIND($ν4, $ν5, Δ);
+        PUT($ν5, 00-00-00-00-00-00-00-29);
+        ADD($ν6);
+        BIND($ν6, $ν4, inc);
+        ADD($ν7);
+        BIND($ν7, $ν6, β);
+        BIND(ν0, $ν7, foo);
+        ",
+    );
+    let mut g = Sodg::empty();
+    s.deploy_to(&mut g)?;
+    let mut uni = Universe::from_graph(g);
+    uni.register("inc", inc);
+    Ok(uni)
+}
+
+#[test]
+fn fails_when_steps_are_exhausted() -> Result<()> {
+    let mut uni = copy_of_inc()?.with_max_steps(1);
+    let err = uni.dataize("Φ.foo").unwrap_err();
+    assert!(
+        err.chain()
+            .any(|e| e.to_string().contains("the budget of steps is exhausted")),
+        "Unexpected error: {err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn fails_when_vertices_are_exhausted() -> Result<()> {
+    let mut uni = copy_of_inc()?.with_max_vertices(2);
+    let err = uni.dataize("Φ.foo").unwrap_err();
+    assert!(
+        err.chain().any(|e| e
+            .to_string()
+            .contains("the budget of vertices is exhausted")),
+        "Unexpected error: {err}"
+    );
+    Ok(())
+}
+
+#[test]
+fn renews_budgets_for_each_dataization() -> Result<()> {
+    let mut uni = copy_of_inc()?.with_max_steps(3);
+    for _ in 0..5 {
+        assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    }
+    Ok(())
+}
+
+/// Make a Universe where `foo` is a copy of `bar`, which is `42`.
+#[cfg(test)]
+fn copy_of_bar() -> Result<Universe> {
+    let mut g = Sodg::empty();
+    Script::from_str(
+        "
+        ADD(0);
+        ADD($v1);
+        BIND(0, $v1, bar);
+        ADD($v2);
+        BIND($v1, $v2, Δ);
+        PUT($v2, 00-00-00-00-00-00-00-2A);
+        ADD($v3);
+        BIND(0, $v3, foo);
+        BIND($v3, $v1, π);
+        ",
+    )
+    .deploy_to(&mut g)?;
+    Ok(Universe::from_graph(g))
+}
+
+#[test]
+fn memoizes_pure_objects() -> Result<()> {
+    let mut uni = copy_of_bar()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let before = uni.g.len();
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert_eq!(before, uni.g.len());
+    Ok(())
+}
+
+#[test]
+fn grows_without_memo() -> Result<()> {
+    let mut uni = copy_of_bar()?.with_memo(false);
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let before = uni.g.len();
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert!(uni.g.len() > before);
+    Ok(())
+}
+
+#[test]
+fn forgets_memo_on_put() -> Result<()> {
+    let mut uni = copy_of_bar()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let d = uni.find("Φ.bar.Δ")?;
+    uni.put(d, Hex::from(7))?;
+    assert_eq!(7, uni.dataize("Φ.foo")?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn forgets_memo_on_bind() -> Result<()> {
+    let mut uni = copy_of_bar()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let baz = uni.add()?;
+    let d = uni.add()?;
+    uni.bind(baz, d, "Δ")?;
+    uni.put(d, Hex::from(7))?;
+    let foo = uni.find("Φ.foo")?;
+    uni.bind(foo, baz, "π")?;
+    assert_eq!(7, uni.dataize("Φ.foo")?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn collects_garbage() -> Result<()> {
+    let mut uni = copy_of_bar()?.with_memo(false);
+    let lost = uni.add()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    let before = uni.g.len();
+    let freed = uni.gc()?;
+    assert!(freed > 0);
+    assert_eq!(before - freed, uni.g.len());
+    assert!(uni.kid(lost, "ρ").is_none());
+    assert_eq!(0, uni.gc()?);
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    Ok(())
+}
+
+#[test]
+fn refuses_to_collect_garbage_inside_atom() -> Result<()> {
+    let mut uni = copy_of_inc()?;
+    uni.register("inc", |uni: &mut Universe, v: u32| {
+        uni.gc()?;
+        Ok(v)
+    });
+    let err = uni.dataize("Φ.foo").unwrap_err();
+    assert!(
+        format!("{err:#}").contains("in the middle of a dataization"),
+        "{err:#}"
+    );
+    Ok(())
+}
+
+#[test]
+fn keeps_memoized_data() -> Result<()> {
+    let mut uni = copy_of_bar()?;
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    uni.gc()?;
+    let before = uni.g.len();
+    assert_eq!(42, uni.dataize("Φ.foo")?.to_i64()?);
+    assert_eq!(before, uni.g.len());
+    Ok(())
+}
+
+#[test]
+fn reports_missing_atom() -> Result<()> {
+    let mut uni = copy_of_inc()?;
+    uni.atoms.clear();
+    let err = uni.dataize("Φ.foo").unwrap_err();
+    match err.downcast_ref::<ReoError>() {
+        Some(ReoError::MissingAtom { atom, .. }) => assert_eq!("inc", atom),
+        _ => panic!("Unexpected error: {err:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn reports_failed_atom() -> Result<()> {
+    let mut uni = copy_of_inc()?;
+    uni.register("inc", |_: &mut Universe, _| Err(anyhow!("boom")));
+    let err = uni.dataize("Φ.foo").unwrap_err();
+    match err.downcast_ref::<ReoError>() {
+        Some(ReoError::AtomFailure { atom, cause, .. }) => {
+            assert_eq!("inc", atom);
+            assert_eq!("boom", cause);
+        }
+        _ => panic!("Unexpected error: {err:?}"),
+    }
     Ok(())
 }
 
@@ -642,34 +1181,41 @@ fn quick_tests() -> Result<()> {
         let mut s = Script::from_str(fs::read_to_string(&path)?.as_str());
         let mut g = Sodg::empty();
         s.deploy_to(&mut g)?;
-        let p = format!("target/surge/{}", name);
-        let home = Path::new(p.as_str());
-        if home.exists() {
-            fs::remove_dir_all(home).context(anyhow!(
-                "Can't delete directory '{}'",
-                home.to_str().unwrap()
-            ))?;
-        }
-        let mut uni = Universe::from_graph(g).with_snapshots(home);
+        let home = Path::new("target/surge").join(format!("{name}.html"));
+        let mut uni = Universe::from_graph(g)
+            .with_snapshot_sink(Rc::new(RefCell::new(HtmlSink::new(home.as_path()))));
+        crate::org::eolang::register(&mut uni);
         uni.register("inc", inc);
         uni.register("times", times);
-        let r = uni.dataize("Φ.foo");
-        uni.exit_it("The end".to_string())?;
-        if r.is_err() {
-            assert!(Command::new("make")
-                .current_dir(home)
-                .spawn()
-                .unwrap()
-                .wait()
-                .unwrap()
-                .success());
-        }
-        let hex = r.context(anyhow!("Failure in {path}"))?;
+        let hex = uni
+            .dataize("Φ.foo")
+            .context(anyhow!("Failure in {path}, see {}", home.display()))?;
         assert_eq!(42, hex.to_i64()?, "Failure in {path}");
     }
     Ok(())
 }
 
+#[test]
+fn quick_tests_with_gc() -> Result<()> {
+    for path in sodg_scripts_in_dir("quick-tests") {
+        trace!("#quick_tests_with_gc: {path}");
+        let mut s = Script::from_str(fs::read_to_string(&path)?.as_str());
+        let mut g = Sodg::empty();
+        s.deploy_to(&mut g)?;
+        let mut uni = Universe::from_graph(g).with_gc_threshold(1);
+        crate::org::eolang::register(&mut uni);
+        uni.register("inc", inc);
+        uni.register("times", times);
+        assert_eq!(
+            42,
+            uni.dataize("Φ.foo")
+                .context(anyhow!("Failed in {path}"))?
+                .to_i64()?
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn quick_errors() -> Result<()> {
     for path in sodg_scripts_in_dir("quick-errors") {
@@ -678,6 +1224,7 @@ fn quick_errors() -> Result<()> {
         let mut g = Sodg::empty();
         s.deploy_to(&mut g)?;
         let mut uni = Universe::from_graph(g);
+        crate::org::eolang::register(&mut uni);
         uni.register("inc", inc);
         uni.register("times", times);
         assert!(
@@ -687,3 +1234,62 @@ fn quick_errors() -> Result<()> {
     }
     Ok(())
 }
+
+/// Make `Φ.foo` with three ASCII attributes: `x` and `z` are nils,
+/// while `y` is not.
+#[cfg(test)]
+fn with_free_attributes() -> Result<(Universe, u32)> {
+    let mut s = Script::from_str(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, x);
+        BIND($ν2, $ν1, ρ);
+        ADD($ν3);
+        BIND($ν1, $ν3, y);
+        ADD($ν4);
+        BIND($ν3, $ν4, Δ);
+        PUT($ν4, 00-2A);
+        ADD($ν5);
+        BIND($ν1, $ν5, z);
+        BIND($ν5, $ν1, ρ);
+        ",
+    );
+    let mut g = Sodg::empty();
+    s.deploy_to(&mut g)?;
+    let v = g.kid(0, "foo").unwrap();
+    Ok((Universe::from_graph(g), v))
+}
+
+#[test]
+fn ties_alpha_to_nth_free_attribute() -> Result<()> {
+    let (mut uni, v) = with_free_attributes()?;
+    assert_eq!("x", uni.tie(v, "α0".to_string())?);
+    assert_eq!("z", uni.tie(v, "α1".to_string())?);
+    assert_eq!("x", uni.tie(v, "x".to_string())?);
+    Ok(())
+}
+
+#[test]
+fn ties_missing_alpha_as_vararg() -> Result<()> {
+    let (mut uni, v) = with_free_attributes()?;
+    assert_eq!("α2", uni.tie(v, "α2".to_string())?);
+    assert_eq!("α7", uni.tie(v, "α7".to_string())?);
+    Ok(())
+}
+
+#[test]
+fn refuses_to_tie_to_non_nil_attribute() -> Result<()> {
+    let (mut uni, v) = with_free_attributes()?;
+    let err = uni.tie(v, "y".to_string()).unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<ReoError>(),
+            Some(ReoError::TieFailure { .. })
+        ),
+        "Unexpected error: {err}"
+    );
+    Ok(())
+}
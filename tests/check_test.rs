@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use tempfile::TempDir;
+
+#[test]
+fn passes_when_atoms_are_available() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("good.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, λ);
+        PUT($ν2, 6F-72-67-2E-65-6F-6C-61-6E-67-2E-69-6F-2E-73-74-64-6F-75-74);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("check")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout("");
+    Ok(())
+}
+
+#[test]
+fn lists_missing_atoms() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("bad.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, bar);
+        ADD($ν3);
+        BIND($ν2, $ν3, λ);
+        PUT($ν3, 61-62-73-65-6E-74);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("check")
+        .arg(bin.as_os_str())
+        .assert()
+        .failure()
+        .stdout("absent at Φ.foo.bar (ν2)\n")
+        .stderr(predicate::str::contains("1 atom(s) are missing"));
+    Ok(())
+}
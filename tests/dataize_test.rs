@@ -5,6 +5,7 @@ mod common;
 
 use crate::common::compiler::compile_one;
 use anyhow::Result;
+use predicates::prelude::predicate;
 use tempfile::TempDir;
 
 #[test]
@@ -54,3 +55,318 @@ fn dumps_after_mistake() -> Result<()> {
     assert!(dump.exists());
     Ok(())
 }
+
+#[test]
+fn reports_exhausted_depth() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("cycle.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, φ);
+        BIND($ν2, $ν1, φ);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--max-depth=8")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--max-depth is 8"));
+    Ok(())
+}
+
+#[test]
+fn dataizes_without_memo() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("copy.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--no-memo")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("00-00-00-00-00-00-00-2A"));
+    Ok(())
+}
+
+#[test]
+fn dataizes_with_gc_threshold() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("copy.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--gc-threshold=1")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("00-00-00-00-00-00-00-2A"));
+    Ok(())
+}
+
+#[test]
+fn saves_trace() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("copy.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    for (format, prefix) in [("jsonl", "{\"ev\":\"enter\""), ("chrome", "[")] {
+        let trace = tmp.path().join(format!("trace.{format}"));
+        assert_cmd::Command::cargo_bin("reo")
+            .unwrap()
+            .arg("dataize")
+            .arg(format!("--trace-out={}", trace.display()))
+            .arg(format!("--trace-format={format}"))
+            .arg(bin.as_os_str())
+            .arg("foo")
+            .assert()
+            .success();
+        let text = std::fs::read_to_string(trace)?;
+        assert!(text.starts_with(prefix), "{text}");
+        assert!(text.contains("\"apply\""), "{text}");
+    }
+    Ok(())
+}
+
+#[test]
+fn saves_snapshots() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("copy.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    for (format, file) in [("html", "index.html"), ("dot", "1.dot")] {
+        let home = tmp.path().join(format);
+        assert_cmd::Command::cargo_bin("reo")
+            .unwrap()
+            .current_dir(tmp.path())
+            .arg("dataize")
+            .arg(format!("--snapshots={}", home.display()))
+            .arg(format!("--snapshot-format={format}"))
+            .arg(bin.as_os_str())
+            .arg("foo")
+            .assert()
+            .success();
+        assert!(home.join(file).exists(), "{file} is absent");
+    }
+    Ok(())
+}
+
+#[test]
+fn prints_typed_results() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("typed.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, int);
+        ADD($ν4);
+        BIND(ν0, $ν4, foo);
+        BIND($ν4, $ν3, π);
+        ADD($ν5);
+        BIND($ν4, $ν5, Δ);
+        PUT($ν5, 00-00-00-00-00-00-00-2A);
+        ",
+        bin.clone(),
+    )?;
+    for (args, out) in [
+        (vec![], "00-00-00-00-00-00-00-2A\n"),
+        (vec!["--as=auto"], "42\n"),
+        (vec!["--as=float"], "2.08e-322\n"),
+        (
+            vec!["--as=auto", "--json"],
+            "{\"type\":\"int\",\"bytes\":\"00-00-00-00-00-00-00-2A\",\"value\":42}\n",
+        ),
+    ] {
+        assert_cmd::Command::cargo_bin("reo")
+            .unwrap()
+            .arg("dataize")
+            .args(args)
+            .arg(bin.as_os_str())
+            .arg("foo")
+            .assert()
+            .success()
+            .stdout(out);
+    }
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--as=string")
+        .arg(bin.as_os_str())
+        .arg("org")
+        .assert()
+        .failure();
+    Ok(())
+}
+
+#[test]
+fn passes_arguments_to_program() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("args.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, array);
+        ADD($ν4);
+        BIND($ν2, $ν4, string);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--as=string")
+        .arg(bin.as_os_str())
+        .arg("org.eolang.sys.args.α1")
+        .arg("--")
+        .arg("first")
+        .arg("--second")
+        .assert()
+        .success()
+        .stdout("--second\n");
+    Ok(())
+}
+
+#[test]
+fn exposes_no_arguments_to_program() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("args.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, array);
+        ADD($ν4);
+        BIND($ν2, $ν4, int);
+        ADD($ν5);
+        BIND($ν3, $ν5, length);
+        BIND($ν5, $ν3, ρ);
+        ADD($ν6);
+        BIND($ν5, $ν6, λ);
+        PUT($ν6, 6F-72-67-2E-65-6F-6C-61-6E-67-2E-61-72-72-61-79-24-6C-65-6E-67-74-68);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--as=int")
+        .arg(bin.as_os_str())
+        .arg("org.eolang.sys.args.length")
+        .assert()
+        .success()
+        .stdout("0\n");
+    Ok(())
+}
+
+#[test]
+fn saves_balanced_trace_of_failure() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("cycle.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, φ);
+        BIND($ν2, $ν1, φ);
+        ",
+        bin.clone(),
+    )?;
+    let trace = tmp.path().join("trace.json");
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg("--max-depth=8")
+        .arg(format!("--trace-out={}", trace.display()))
+        .arg("--trace-format=chrome")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .failure();
+    let text = std::fs::read_to_string(trace)?;
+    assert!(text.trim_end().ends_with(']'), "{text}");
+    assert_eq!(
+        text.matches("\"ph\":\"B\"").count(),
+        text.matches("\"ph\":\"E\"").count(),
+        "{text}"
+    );
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use tempfile::TempDir;
+
+#[test]
+fn debugs_simple_program() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")?
+        .arg("debug")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .write_stdin("step\nprint Φ.bar\ncontinue\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Φ.bar is ν1"))
+        .stdout(predicate::str::contains("00-00-00-00-00-00-00-2A"));
+    Ok(())
+}
+
+#[test]
+fn quits_debugging() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, φ);
+        ADD($ν3);
+        BIND($ν2, $ν3, Δ);
+        PUT($ν3, 00-00-00-00-00-00-00-2A);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")?
+        .arg("debug")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .write_stdin("quit\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("stopped by the debugger"));
+    Ok(())
+}
+
+#[test]
+fn limits_depth_of_debugging() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("cycle.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, φ);
+        BIND($ν2, $ν1, φ);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")?
+        .arg("debug")
+        .arg("--max-depth=8")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .write_stdin("continue\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--max-depth is 8"));
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn prints_script() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("first.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, +package);
+        PUT($ν1, 6F-72-67);
+        ADD($ν2);
+        BIND(ν0, $ν2, foo);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("decompile")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("ADD(ν0);\n"))
+        .stdout(predicate::str::contains("# +package: org\n"))
+        .stdout(predicate::str::contains("BIND(ν0, $ν2, foo);\n"));
+    Ok(())
+}
+
+#[test]
+fn round_trips_through_compile() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("first.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, +home);
+        PUT($ν1, 68-74-74-70-73);
+        ADD($ν2);
+        BIND(ν0, $ν2, bar);
+        ADD($ν3);
+        BIND($ν2, $ν3, Δ);
+        PUT($ν3, 00-00-00-00-00-00-00-2A);
+        ADD($ν4);
+        BIND(ν0, $ν4, foo);
+        BIND($ν4, $ν2, π);
+        BIND($ν4, ν0, ρ);
+        ",
+        bin.clone(),
+    )?;
+    let mut scripts = vec![];
+    for i in 0..2 {
+        let src = tmp.path().join(format!("{i}.sodg"));
+        assert_cmd::Command::cargo_bin("reo")
+            .unwrap()
+            .arg("decompile")
+            .arg(bin.as_os_str())
+            .arg(src.as_os_str())
+            .assert()
+            .success();
+        assert_cmd::Command::cargo_bin("reo")
+            .unwrap()
+            .arg("compile")
+            .arg(src.as_os_str())
+            .arg(bin.as_os_str())
+            .assert()
+            .success();
+        scripts.push(fs::read_to_string(src)?);
+    }
+    assert_eq!(scripts[0], scripts[1]);
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("dataize")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .success()
+        .stdout("00-00-00-00-00-00-00-2A\n");
+    Ok(())
+}
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use anyhow::Result;
+use predicates::prelude::predicate;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn save(path: &Path, sodg: &str) -> Result<()> {
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(path, sodg)?;
+    Ok(())
+}
+
+/// Save the `.sodg` files of a "Hello, world!" program, the way
+/// `eoc sodg` would, into the `.eoc` directory.
+fn save_hello_world(home: &Path) -> Result<()> {
+    let sodg = home.join(".eoc/sodg");
+    save(
+        sodg.join("org/eolang/bool.sodg").as_path(),
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, bool);
+        ",
+    )?;
+    save(
+        sodg.join("org/eolang/io/stdout.sodg").as_path(),
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, io);
+        ADD($ν4);
+        BIND($ν3, $ν4, stdout);
+        ADD($ν5);
+        BIND($ν4, $ν5, λ);
+        PUT($ν5, 6F-72-67-2E-65-6F-6C-61-6E-67-2E-69-6F-2E-73-74-64-6F-75-74);
+        ",
+    )?;
+    save(
+        sodg.join("app.sodg").as_path(),
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, app);
+        ADD($ν2);
+        BIND($ν1, $ν2, φ);
+        ADD($ν3);
+        BIND($ν2, $ν3, π);
+        ADD($ν4);
+        BIND($ν3, $ν4, β);
+        ADD($ν5);
+        BIND($ν4, $ν5, stdout);
+        ADD($ν6);
+        BIND($ν5, $ν6, β);
+        ADD($ν7);
+        BIND($ν6, $ν7, io);
+        ADD($ν8);
+        BIND($ν7, $ν8, β);
+        ADD($ν9);
+        BIND($ν8, $ν9, eolang);
+        ADD($ν10);
+        BIND($ν9, $ν10, β);
+        BIND($ν10, ν0, org);
+        ADD($ν13);
+        BIND($ν2, $ν13, α0);
+        ADD($ν14);
+        BIND($ν13, $ν14, Δ);
+        PUT($ν14, 48-65-6C-6C-6F-2C-20-77-6F-72-6C-64-21-0A);
+        ",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn runs_hello_world_from_eoc_directory() -> Result<()> {
+    let tmp = TempDir::new()?;
+    save_hello_world(tmp.path())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("dataize")
+        .arg("--eoc")
+        .arg(".eoc")
+        .arg("app")
+        .assert()
+        .success()
+        .stdout("Hello, world!\n01\n");
+    Ok(())
+}
+
+#[test]
+fn fails_when_eoc_directory_is_absent() -> Result<()> {
+    let tmp = TempDir::new()?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("dataize")
+        .arg("--eoc=.eoc")
+        .arg("app")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("eoc sodg"));
+    Ok(())
+}
+
+#[test]
+fn rejects_eoc_where_it_is_not_supported() -> Result<()> {
+    let tmp = TempDir::new()?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("lint")
+        .arg("--eoc=.eoc")
+        .arg("app.reo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--eoc"));
+    Ok(())
+}
+
+#[test]
+fn takes_eoc_after_object() -> Result<()> {
+    let tmp = TempDir::new()?;
+    save_hello_world(tmp.path())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("dataize")
+        .arg("app")
+        .arg("--eoc=.eoc")
+        .assert()
+        .success()
+        .stdout("Hello, world!\n01\n");
+    Ok(())
+}
+
+#[test]
+fn rejects_file_with_eoc() -> Result<()> {
+    let tmp = TempDir::new()?;
+    save_hello_world(tmp.path())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("dataize")
+        .arg("--eoc")
+        .arg(".eoc")
+        .arg("app.reo")
+        .arg("app")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("only the object name is expected"));
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use tempfile::TempDir;
+
+#[test]
+fn passes_clean_graph() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("clean.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("lint")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout("");
+    Ok(())
+}
+
+#[test]
+fn fails_on_errors() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("broken.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, λ);
+        ADD($ν3);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("lint")
+        .arg(bin.as_os_str())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "ν1: error: λ points to ν2, which has no data [lambda-utf8]",
+        ))
+        .stdout(predicate::str::contains(
+            "ν3: warning: it is not reachable from ν0 [unreachable]",
+        ))
+        .stderr(predicate::str::contains("Found 1 error(s)"));
+    Ok(())
+}
+
+#[test]
+fn passes_empty_string() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("empty.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, foo);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("lint")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "ν1: warning: Δ points to ν2, which has no data [delta-data]",
+        ));
+    Ok(())
+}
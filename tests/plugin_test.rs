@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::{anyhow, Result};
+use predicates::prelude::predicate;
+use regex::Regex;
+use std::path::PathBuf;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Build the `reo-double` plugin from the workspace, with the same
+/// profile as the tests, and return the path of the shared library,
+/// as Cargo reports it.
+fn double_plugin() -> Result<PathBuf> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut cmd = Command::new(cargo);
+    cmd.arg("build")
+        .arg("--package")
+        .arg("reo-double")
+        .arg("--message-format=json");
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    let out = cmd.output()?;
+    assert!(out.status.success());
+    let name = format!(
+        "{}reo_double{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    );
+    let quoted = Regex::new(r#""((?:[^"\\]|\\.)*)""#)?;
+    String::from_utf8(out.stdout)?
+        .lines()
+        .filter(|l| l.contains("\"reason\":\"compiler-artifact\""))
+        .flat_map(|l| {
+            quoted
+                .captures_iter(l)
+                .map(|c| c[1].replace("\\\\", "\\"))
+                .collect::<Vec<String>>()
+        })
+        .find(|p| p.ends_with(name.as_str()))
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("Cargo didn't build {name}"))
+}
+
+#[test]
+fn dataizes_with_plugin_atom() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, org);
+        ADD($ν2);
+        BIND($ν1, $ν2, eolang);
+        ADD($ν3);
+        BIND($ν2, $ν3, int);
+        ADD($ν4);
+        BIND($ν1, $ν4, example);
+        ADD($ν5);
+        BIND($ν4, $ν5, double);
+        ADD($ν6);
+        BIND($ν5, $ν6, λ);
+        PUT($ν6, 6F-72-67-2E-65-78-61-6D-70-6C-65-2E-64-6F-75-62-6C-65);
+        ADD($ν7);
+        BIND($ν7, $ν5, π);
+        ADD($ν8);
+        BIND($ν7, $ν8, α0);
+        ADD($ν9);
+        BIND($ν8, $ν9, Δ);
+        PUT($ν9, 00-00-00-00-00-00-00-15);
+        BIND(ν0, $ν7, foo);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")?
+        .arg("dataize")
+        .arg(format!("--plugin={}", double_plugin()?.display()))
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("00-00-00-00-00-00-00-2A"));
+    Ok(())
+}
+
+#[test]
+fn fails_on_absent_plugin() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one("ADD(ν0);", bin.clone())?;
+    assert_cmd::Command::cargo_bin("reo")?
+        .arg("dataize")
+        .arg("--plugin=/absent/libfoo.so")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Can't load plugin"));
+    Ok(())
+}
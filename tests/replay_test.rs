@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Dataize `foo`, which is a copy of `bar`, saving the journal
+/// of snapshots, and return the path of it.
+fn journal(tmp: &Path) -> Result<PathBuf> {
+    let bin = tmp.join("copy.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, bar);
+        ADD($ν2);
+        BIND($ν1, $ν2, Δ);
+        PUT($ν2, 00-00-00-00-00-00-00-2A);
+        ADD($ν3);
+        BIND(ν0, $ν3, foo);
+        BIND($ν3, $ν1, π);
+        ",
+        bin.clone(),
+    )?;
+    let home = tmp.join("snapshots");
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp)
+        .arg("dataize")
+        .arg(format!("--snapshots={}", home.display()))
+        .arg("--snapshot-format=journal")
+        .arg(bin.as_os_str())
+        .arg("foo")
+        .assert()
+        .success();
+    Ok(home.join("journal.txt"))
+}
+
+#[test]
+fn replays_first_step() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let journal = journal(tmp.path())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("replay")
+        .arg("--step=1")
+        .arg(journal.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("digraph"))
+        .stdout(predicate::str::contains("v3 -> v1 [label=\"π\""));
+    Ok(())
+}
+
+#[test]
+fn replays_into_binary() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let journal = journal(tmp.path())?;
+    let bin = tmp.path().join("last.reo");
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("replay")
+        .arg(journal.as_os_str())
+        .arg(bin.as_os_str())
+        .assert()
+        .success();
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("inspect")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Total vertices: "));
+    Ok(())
+}
+
+#[test]
+fn fails_on_absent_step() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let journal = journal(tmp.path())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .current_dir(tmp.path())
+        .arg("replay")
+        .arg("--step=100000")
+        .arg(journal.as_os_str())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("There is no step no.100000"));
+    Ok(())
+}
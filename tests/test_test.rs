@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: Copyright (c) 2022-2026 Yegor Bugayenko
+// SPDX-License-Identifier: MIT
+
+mod common;
+
+use crate::common::compiler::compile_one;
+use anyhow::Result;
+use predicates::prelude::predicate;
+use std::fs;
+use tempfile::TempDir;
+
+const TESTS: &str = "
+    ADD(ν0);
+    ADD($ν1);
+    BIND(ν0, $ν1, pkg);
+    ADD($ν2);
+    BIND($ν2, $ν1, ρ);
+    BIND($ν1, $ν2, good);
+    ADD($ν3);
+    BIND($ν2, $ν3, Δ);
+    PUT($ν3, 00-2A);
+    ADD($ν4);
+    BIND($ν4, $ν1, ρ);
+    BIND($ν1, $ν4, bad);
+    ADD($ν5);
+    BIND($ν4, $ν5, Δ);
+    PUT($ν5, 00-07);
+    ADD($ν6);
+    BIND($ν6, $ν3, Δ);
+    BIND($ν2, $ν6, expected);
+    BIND($ν4, $ν6, expected);
+";
+
+#[test]
+fn runs_filtered_tests() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(TESTS, bin.clone())?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("test")
+        .arg("--filter=pkg.g*")
+        .arg(bin.as_os_str())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("PASS pkg.good ("))
+        .stdout(predicate::str::contains("1 test(s), 1 passed, 0 failed"));
+    Ok(())
+}
+
+#[test]
+fn reports_failures_to_junit() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(TESTS, bin.clone())?;
+    let xml = tmp.path().join("junit.xml");
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("test")
+        .arg(format!("--junit={}", xml.display()))
+        .arg(bin.as_os_str())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAIL pkg.bad ("))
+        .stdout(predicate::str::contains("Expected 00-2A, but got 00-07"))
+        .stdout(predicate::str::contains("2 test(s), 1 passed, 1 failed"))
+        .stderr(predicate::str::contains("1 test(s) failed"));
+    let report = fs::read_to_string(xml)?;
+    assert!(report.contains("tests=\"2\" failures=\"1\""), "{report}");
+    assert!(report.contains("<testcase name=\"pkg.good\""), "{report}");
+    Ok(())
+}
+
+#[test]
+fn limits_depth_of_tests() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let bin = tmp.path().join("app.reo");
+    compile_one(
+        "
+        ADD(ν0);
+        ADD($ν1);
+        BIND(ν0, $ν1, pkg);
+        ADD($ν2);
+        BIND($ν2, $ν1, ρ);
+        BIND($ν1, $ν2, cycle);
+        ADD($ν3);
+        BIND($ν2, $ν3, φ);
+        BIND($ν3, $ν2, φ);
+        ADD($ν4);
+        BIND($ν2, $ν4, expected);
+        ADD($ν5);
+        BIND($ν4, $ν5, Δ);
+        PUT($ν5, 00-2A);
+        ",
+        bin.clone(),
+    )?;
+    assert_cmd::Command::cargo_bin("reo")
+        .unwrap()
+        .arg("test")
+        .arg("--max-depth=8")
+        .arg(bin.as_os_str())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAIL pkg.cycle ("))
+        .stdout(predicate::str::contains("--max-depth is 8"));
+    Ok(())
+}